@@ -0,0 +1,106 @@
+use crate::models::{convert_schema_to_openapi_31, Components, Model, OpgModel};
+use serde_json::{to_value, Map, Value};
+
+pub const JSON_SCHEMA_DRAFT: &str = "https://json-schema.org/draft/2020-12/schema";
+pub const JSON_SCHEMA_REFERENCE_PREFIX: &str = "#/$defs/";
+
+/// Generate self-contained JSON Schema for the specified model
+///
+/// Referenced models are placed into `$defs`
+pub fn json_schema<T>() -> Value
+where
+    T: OpgModel + ?Sized,
+{
+    json_schema_with_prefix::<T, _>(JSON_SCHEMA_REFERENCE_PREFIX)
+}
+
+/// Generate JSON Schema for the specified model using custom reference prefix
+///
+/// Referenced models are placed into `$defs`, so the prefix should point to
+/// the location where this schema is embedded. Links are serialized with
+/// [`SCHEMA_REFERENCE_PREFIX`](crate::SCHEMA_REFERENCE_PREFIX) and then rewritten to use this prefix
+pub fn json_schema_with_prefix<T, P>(prefix: P) -> Value
+where
+    T: OpgModel + ?Sized,
+    P: AsRef<str>,
+{
+    let mut cx = Components::new();
+    let mut reference = cx.mention_schema::<T>(false, &Default::default());
+
+    // tuples are emitted with `prefixItems` in JSON Schema 2020-12
+    reference.for_each_model_mut(&mut Model::use_prefix_items);
+    cx.schemas
+        .values_mut()
+        .for_each(|model| model.for_each_model_mut(&mut Model::use_prefix_items));
+
+    let mut root = to_value(&reference).expect("model must be serializable into JSON");
+    let definitions = to_value(&cx.schemas).expect("models must be serializable into JSON");
+
+    convert_schema_to_openapi_31(&mut root);
+    rewrite_links(&mut root, prefix.as_ref());
+
+    let mut schema = Map::new();
+    schema.insert("$schema".to_owned(), JSON_SCHEMA_DRAFT.into());
+    if let Value::Object(root) = root {
+        schema.extend(root);
+    }
+
+    if let Value::Object(mut definitions) = definitions {
+        if !definitions.is_empty() {
+            definitions.values_mut().for_each(|definition| {
+                convert_schema_to_openapi_31(definition);
+                rewrite_links(definition, prefix.as_ref());
+            });
+            schema.insert("$defs".to_owned(), Value::Object(definitions));
+        }
+    }
+
+    Value::Object(schema)
+}
+
+/// Replace [`SCHEMA_REFERENCE_PREFIX`](crate::SCHEMA_REFERENCE_PREFIX) of schema links with the specified prefix
+///
+/// Links are always serialized with the OpenAPI components prefix, so they are rewritten
+/// afterwards. Only schema keywords are visited, values like `default` or `enum` are kept as is
+fn rewrite_links(schema: &mut Value, prefix: &str) {
+    fn rewrite(link: &mut Value, prefix: &str) {
+        if let Value::String(link) = link {
+            if let Some(name) = link.strip_prefix(crate::SCHEMA_REFERENCE_PREFIX) {
+                *link = format!("{}{}", prefix, name);
+            }
+        }
+    }
+
+    let schema = match schema.as_object_mut() {
+        Some(schema) => schema,
+        None => return,
+    };
+
+    if let Some(link) = schema.get_mut("$ref") {
+        rewrite(link, prefix);
+    }
+    if let Some(Value::Object(mapping)) = schema
+        .get_mut("discriminator")
+        .and_then(|discriminator| discriminator.get_mut("mapping"))
+    {
+        mapping.values_mut().for_each(|link| rewrite(link, prefix));
+    }
+
+    for key in &["items", "additionalProperties"] {
+        if let Some(item) = schema.get_mut(*key) {
+            rewrite_links(item, prefix);
+        }
+    }
+    if let Some(Value::Object(properties)) = schema.get_mut("properties") {
+        properties
+            .values_mut()
+            .for_each(|property| rewrite_links(property, prefix));
+    }
+    for key in &["oneOf", "allOf", "anyOf", "prefixItems"] {
+        if let Some(Value::Array(items)) = schema.get_mut(*key) {
+            items
+                .iter_mut()
+                .for_each(|item| rewrite_links(item, prefix));
+        }
+    }
+}
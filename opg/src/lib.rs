@@ -1,14 +1,23 @@
 use std::borrow::Cow;
 
+pub use json_schema::*;
 pub use macros::*;
 pub use models::*;
 pub use opg_derive::OpgModel;
+pub use swagger2::{Swagger2Document, Swagger2Warning, Swagger2WarningKind};
 
+pub mod json_schema;
 pub mod macros;
 pub mod models;
+#[cfg(any(feature = "chrono", feature = "time"))]
+pub mod schema_with;
+pub mod swagger2;
 
 pub const OPENAPI_VERSION: &str = "3.0.3";
+pub const OPENAPI_31_VERSION: &str = "3.1.0";
+pub const JSON_SCHEMA_DIALECT: &str = "https://spec.openapis.org/oas/3.1/dialect/base";
 pub const SCHEMA_REFERENCE_PREFIX: &str = "#/components/schemas/";
+pub const DEFAULT_MEDIA_TYPE: &str = "application/json";
 
 impl_opg_model!(string(always_inline, "char"): char);
 impl_opg_model!(string(always_inline): str);
@@ -22,6 +31,8 @@ impl_opg_model!(integer(always_inline, "int32"): i32);
 impl_opg_model!(integer(always_inline, "uint32"): u32);
 impl_opg_model!(integer(always_inline, "int64"): i64);
 impl_opg_model!(integer(always_inline, "uint64"): u64);
+impl_opg_model!(integer(always_inline, "int128"): i128);
+impl_opg_model!(integer(always_inline, "uint128"): u128);
 impl_opg_model!(integer(always_inline): isize);
 impl_opg_model!(integer(always_inline): usize);
 
@@ -43,6 +54,22 @@ impl_opg_model!(integer(always_inline): std::sync::atomic::AtomicUsize);
 
 impl_opg_model!(boolean(always_inline): std::sync::atomic::AtomicBool);
 
+impl_opg_model!(integer(always_inline, "int8"): std::num::NonZeroI8);
+impl_opg_model!(integer(always_inline, "uint8", minimum = 1): std::num::NonZeroU8);
+impl_opg_model!(integer(always_inline, "int16"): std::num::NonZeroI16);
+impl_opg_model!(integer(always_inline, "uint16", minimum = 1): std::num::NonZeroU16);
+impl_opg_model!(integer(always_inline, "int32"): std::num::NonZeroI32);
+impl_opg_model!(integer(always_inline, "uint32", minimum = 1): std::num::NonZeroU32);
+impl_opg_model!(integer(always_inline, "int64"): std::num::NonZeroI64);
+impl_opg_model!(integer(always_inline, "uint64", minimum = 1): std::num::NonZeroU64);
+impl_opg_model!(integer(always_inline, "int128"): std::num::NonZeroI128);
+impl_opg_model!(integer(always_inline, "uint128", minimum = 1): std::num::NonZeroU128);
+impl_opg_model!(integer(always_inline): std::num::NonZeroIsize);
+impl_opg_model!(integer(always_inline, minimum = 1): std::num::NonZeroUsize);
+
+impl_opg_model!(string(always_inline): std::path::Path);
+impl_opg_model!(string(always_inline): std::path::PathBuf);
+
 impl_opg_model!(generic_simple(?Sized): &T);
 impl_opg_model!(generic_simple(?Sized): &mut T);
 impl_opg_model!(generic_simple(?Sized): Box<T>);
@@ -50,6 +77,10 @@ impl_opg_model!(generic_simple(?Sized): std::rc::Rc<T>);
 impl_opg_model!(generic_simple(?Sized): std::sync::Arc<T>);
 impl_opg_model!(generic_simple(?Sized): std::cell::Cell<T>);
 impl_opg_model!(generic_simple(?Sized): std::cell::RefCell<T>);
+impl_opg_model!(generic_simple(?Sized): std::sync::Mutex<T>);
+impl_opg_model!(generic_simple(?Sized): std::sync::RwLock<T>);
+impl_opg_model!(generic_simple: std::num::Wrapping<T>);
+impl_opg_model!(generic_simple: std::cmp::Reverse<T>);
 
 impl_opg_model!(generic_simple(nullable): Option<T>);
 
@@ -68,6 +99,7 @@ impl_opg_model!(generic_tuple: (T1, T2, T3, T4, T5, T6, T7, T8, T9, T10));
 impl_opg_model!(generic_array: [T]);
 impl_opg_model!(generic_array: Vec<T>);
 impl_opg_model!(generic_array: std::collections::HashSet<T>);
+impl_opg_model!(generic_array: std::collections::BTreeSet<T>);
 impl_opg_model!(generic_array: std::collections::LinkedList<T>);
 impl_opg_model!(generic_array: std::collections::VecDeque<T>);
 impl_opg_model!(generic_array: std::collections::BinaryHeap<T>);
@@ -94,10 +126,15 @@ where
     fn get_schema(cx: &mut Components) -> Model {
         Model {
             description: None,
+            example: None,
+            default: None,
+            read_only: false,
+            write_only: false,
             data: ModelData::Single(ModelType {
                 nullable: false,
                 type_description: ModelTypeDescription::Array(ModelArray {
                     items: Box::new(cx.mention_schema::<T>(false, &Default::default())),
+                    ..Default::default()
                 }),
             }),
         }
@@ -120,16 +157,97 @@ impl_opg_model!(generic_dictionary: std::collections::BTreeMap<K, T>);
 impl OpgModel for () {
     fn get_schema(_: &mut Components) -> Model {
         Model {
-            description: Some("Always `null`".to_owned()),
-            data: ModelData::Single(ModelType {
-                nullable: true,
-                type_description: ModelTypeDescription::String(ModelString {
-                    variants: None,
-                    data: ModelSimple {
-                        format: Some("null".to_owned()),
-                        example: None,
-                    },
+            description: None,
+            example: None,
+            default: None,
+            read_only: false,
+            write_only: false,
+            data: ModelData::Null(ModelNull),
+        }
+    }
+
+    #[inline]
+    fn type_name() -> Option<Cow<'static, str>> {
+        None
+    }
+
+    #[inline]
+    fn select_reference(cx: &mut Components, _: bool, params: &ContextParams) -> ModelReference {
+        ModelReference::Inline(Self::get_schema(cx).apply_params(params))
+    }
+}
+
+impl<T> OpgModel for Cow<'_, T>
+where
+    T: OpgModel + ToOwned + ?Sized,
+{
+    fn get_schema(cx: &mut Components) -> Model {
+        <T as OpgModel>::get_schema(cx)
+    }
+
+    #[inline]
+    fn type_name() -> Option<Cow<'static, str>> {
+        <T as OpgModel>::type_name()
+    }
+}
+
+impl<T: ?Sized> OpgModel for std::marker::PhantomData<T> {
+    fn get_schema(cx: &mut Components) -> Model {
+        <() as OpgModel>::get_schema(cx)
+    }
+
+    #[inline]
+    fn type_name() -> Option<Cow<'static, str>> {
+        None
+    }
+
+    #[inline]
+    fn select_reference(cx: &mut Components, _: bool, params: &ContextParams) -> ModelReference {
+        ModelReference::Inline(Self::get_schema(cx).apply_params(params))
+    }
+}
+
+/// Serialized as an externally tagged enum with `Ok` and `Err` variants
+impl<T, E> OpgModel for Result<T, E>
+where
+    T: OpgModel,
+    E: OpgModel,
+{
+    fn get_schema(cx: &mut Components) -> Model {
+        let variant = |name: &str, reference: ModelReference| {
+            let mut properties = std::collections::BTreeMap::new();
+            properties.insert(name.to_owned(), reference);
+
+            ModelReference::Inline(Model {
+                description: None,
+                example: None,
+                default: None,
+                read_only: false,
+                write_only: false,
+                data: ModelData::Single(ModelType {
+                    nullable: false,
+                    type_description: ModelTypeDescription::Object(ModelObject {
+                        properties,
+                        required: vec![name.to_owned()],
+                        ..Default::default()
+                    }),
                 }),
+            })
+        };
+
+        let ok = variant("Ok", cx.mention_schema::<T>(false, &Default::default()));
+        let err = variant("Err", cx.mention_schema::<E>(false, &Default::default()));
+
+        Model {
+            description: None,
+            example: None,
+            default: None,
+            read_only: false,
+            write_only: false,
+            data: ModelData::OneOf(ModelOneOf {
+                one_of: vec![ok, err],
+                discriminator: None,
+                nullable: false,
             }),
         }
     }
@@ -145,20 +263,92 @@ impl OpgModel for () {
     }
 }
 
-#[cfg(feature = "uuid")]
-impl OpgModel for uuid::Uuid {
+impl OpgModel for std::time::Duration {
+    fn get_schema(_: &mut Components) -> Model {
+        describe_type!(object => {
+            properties: {
+                secs[required]: (integer => { format: "uint64" })
+                nanos[required]: (integer => { format: "uint32" })
+            }
+        })
+    }
+
+    #[inline]
+    fn type_name() -> Option<Cow<'static, str>> {
+        None
+    }
+
+    #[inline]
+    fn select_reference(cx: &mut Components, _: bool, params: &ContextParams) -> ModelReference {
+        ModelReference::Inline(Self::get_schema(cx).apply_params(params))
+    }
+}
+
+impl OpgModel for std::time::SystemTime {
     fn get_schema(_: &mut Components) -> Model {
+        describe_type!(object => {
+            description: "Time elapsed since the unix epoch"
+            properties: {
+                secs_since_epoch[required]: (integer => { format: "uint64" })
+                nanos_since_epoch[required]: (integer => { format: "uint32" })
+            }
+        })
+    }
+
+    #[inline]
+    fn type_name() -> Option<Cow<'static, str>> {
+        None
+    }
+
+    #[inline]
+    fn select_reference(cx: &mut Components, _: bool, params: &ContextParams) -> ModelReference {
+        ModelReference::Inline(Self::get_schema(cx).apply_params(params))
+    }
+}
+
+macro_rules! impl_string_opg_model {
+    ($($(#[$meta:meta])* $type:ty => { $($description:tt)* };)*) => {
+        $($(#[$meta])* impl OpgModel for $type {
+            fn get_schema(_: &mut Components) -> Model {
+                describe_type!(string => { $($description)* })
+            }
+
+            #[inline]
+            fn type_name() -> Option<Cow<'static, str>> {
+                None
+            }
+
+            #[inline]
+            fn select_reference(cx: &mut Components, _: bool, params: &ContextParams) -> ModelReference {
+                ModelReference::Inline(Self::get_schema(cx).apply_params(params))
+            }
+        })*
+    };
+}
+
+impl_string_opg_model! {
+    std::net::Ipv4Addr => { format: "ipv4" example: "127.0.0.1" };
+    std::net::Ipv6Addr => { format: "ipv6" example: "::1" };
+    std::net::SocketAddr => { description: "IP address with port" example: "127.0.0.1:8080" };
+    std::net::SocketAddrV4 => { description: "IPv4 address with port" example: "127.0.0.1:8080" };
+    std::net::SocketAddrV6 => { description: "IPv6 address with port" example: "[::1]:8080" };
+}
+
+impl OpgModel for std::net::IpAddr {
+    fn get_schema(cx: &mut Components) -> Model {
         Model {
-            description: Some("UUID ver. 4 [rfc](https://tools.ietf.org/html/rfc4122)".to_owned()),
-            data: ModelData::Single(ModelType {
+            description: None,
+            example: None,
+            default: None,
+            read_only: false,
+            write_only: false,
+            data: ModelData::OneOf(ModelOneOf {
+                one_of: vec![
+                    cx.mention_schema::<std::net::Ipv4Addr>(false, &Default::default()),
+                    cx.mention_schema::<std::net::Ipv6Addr>(false, &Default::default()),
+                ],
+                discriminator: None,
                 nullable: false,
-                type_description: ModelTypeDescription::String(ModelString {
-                    variants: None,
-                    data: ModelSimple {
-                        format: Some("uuid".to_owned()),
-                        example: Some("00000000-0000-0000-0000-000000000000".to_owned()),
-                    },
-                }),
             }),
         }
     }
@@ -174,19 +364,24 @@ impl OpgModel for uuid::Uuid {
     }
 }
 
-#[cfg(feature = "chrono")]
-impl OpgModel for chrono::NaiveDateTime {
+#[cfg(feature = "uuid")]
+impl OpgModel for uuid::Uuid {
     fn get_schema(_: &mut Components) -> Model {
         Model {
-            description: Some("Datetime without timezone".to_owned()),
+            description: Some("UUID ver. 4 [rfc](https://tools.ietf.org/html/rfc4122)".to_owned()),
+            example: Some("00000000-0000-0000-0000-000000000000".into()),
+            default: None,
+            read_only: false,
+            write_only: false,
             data: ModelData::Single(ModelType {
                 nullable: false,
                 type_description: ModelTypeDescription::String(ModelString {
                     variants: None,
                     data: ModelSimple {
-                        format: Some("date".to_owned()),
-                        example: Some("2020-06-26T14:04:20.730045106".to_owned()),
+                        format: Some("uuid".to_owned()),
+                        ..Default::default()
                     },
+                    ..Default::default()
                 }),
             }),
         }
@@ -208,14 +403,19 @@ impl<TZ: chrono::TimeZone> OpgModel for chrono::DateTime<TZ> {
     fn get_schema(_: &mut Components) -> Model {
         Model {
             description: Some("Datetime with timezone".to_owned()),
+            example: Some("2020-06-26T14:04:20.730045106Z".into()),
+            default: None,
+            read_only: false,
+            write_only: false,
             data: ModelData::Single(ModelType {
                 nullable: false,
                 type_description: ModelTypeDescription::String(ModelString {
                     variants: None,
                     data: ModelSimple {
-                        format: Some("date".to_owned()),
-                        example: Some("2020-06-26T14:04:20.730045106Z".to_owned()),
+                        format: Some("date-time".to_owned()),
+                        ..Default::default()
                     },
+                    ..Default::default()
                 }),
             }),
         }
@@ -232,19 +432,133 @@ impl<TZ: chrono::TimeZone> OpgModel for chrono::DateTime<TZ> {
     }
 }
 
+impl_string_opg_model! {
+    #[cfg(feature = "chrono")]
+    chrono::NaiveDateTime => {
+        description: "Datetime without timezone"
+        format: "date-time"
+        example: "2020-06-26T14:04:20.730045106"
+    };
+    #[cfg(feature = "chrono")]
+    chrono::NaiveDate => {
+        description: "Date without timezone"
+        format: "date"
+        example: "2020-06-26"
+    };
+    #[cfg(feature = "chrono")]
+    chrono::NaiveTime => {
+        description: "Time without timezone"
+        format: "time"
+        example: "14:04:20.730045106"
+    };
+}
+
+/// Serialized as a tuple of seconds and nanoseconds
 #[cfg(feature = "chrono")]
-impl OpgModel for chrono::NaiveDate {
+impl OpgModel for chrono::Duration {
+    fn get_schema(cx: &mut Components) -> Model {
+        <(i64, i32) as OpgModel>::get_schema(cx).apply_params(&ContextParams {
+            description: Some("Duration as seconds and nanoseconds".to_owned()),
+            ..Default::default()
+        })
+    }
+
+    #[inline]
+    fn type_name() -> Option<Cow<'static, str>> {
+        None
+    }
+
+    #[inline]
+    fn select_reference(cx: &mut Components, _: bool, params: &ContextParams) -> ModelReference {
+        ModelReference::Inline(Self::get_schema(cx).apply_params(params))
+    }
+}
+
+// `time` types are described in their human-readable representation, which requires
+// the `serde-human-readable` feature of that crate
+impl_string_opg_model! {
+    #[cfg(feature = "time")]
+    time::OffsetDateTime => {
+        description: "Datetime with offset"
+        example: "2020-06-26 14:04:20.730045106 +00:00:00"
+    };
+    #[cfg(feature = "time")]
+    time::PrimitiveDateTime => {
+        description: "Datetime without offset"
+        example: "2020-06-26 14:04:20.730045106"
+    };
+    #[cfg(feature = "time")]
+    time::Date => {
+        description: "Date without offset"
+        format: "date"
+        example: "2020-06-26"
+    };
+    #[cfg(feature = "time")]
+    time::Time => {
+        description: "Time without offset"
+        format: "time"
+        example: "14:04:20.730045106"
+    };
+    #[cfg(feature = "time")]
+    time::Duration => {
+        description: "Duration in seconds with nanosecond precision"
+        example: "1.500000000"
+    };
+}
+
+#[cfg(feature = "serde_json")]
+impl OpgModel for serde_json::Value {
+    fn get_schema(_: &mut Components) -> Model {
+        ModelReference::Any.into_model()
+    }
+
+    #[inline]
+    fn type_name() -> Option<Cow<'static, str>> {
+        None
+    }
+
+    #[inline]
+    fn select_reference(_: &mut Components, _: bool, params: &ContextParams) -> ModelReference {
+        ModelReference::Any.apply_params(params)
+    }
+}
+
+/// Raw JSON is not checked, so it can be anything
+#[cfg(feature = "serde_json")]
+impl OpgModel for serde_json::value::RawValue {
+    fn get_schema(cx: &mut Components) -> Model {
+        <serde_json::Value as OpgModel>::get_schema(cx)
+    }
+
+    #[inline]
+    fn type_name() -> Option<Cow<'static, str>> {
+        None
+    }
+
+    #[inline]
+    fn select_reference(
+        cx: &mut Components,
+        inline: bool,
+        params: &ContextParams,
+    ) -> ModelReference {
+        <serde_json::Value as OpgModel>::select_reference(cx, inline, params)
+    }
+}
+
+#[cfg(feature = "serde_json")]
+impl OpgModel for serde_json::Map<String, serde_json::Value> {
     fn get_schema(_: &mut Components) -> Model {
         Model {
-            description: Some("Date without timezone".to_owned()),
+            description: None,
+            example: None,
+            default: None,
+            read_only: false,
+            write_only: false,
             data: ModelData::Single(ModelType {
                 nullable: false,
-                type_description: ModelTypeDescription::String(ModelString {
-                    variants: None,
-                    data: ModelSimple {
-                        format: Some("date".to_owned()),
-                        example: Some("2020-06-26".to_owned()),
-                    },
+                type_description: ModelTypeDescription::Object(ModelObject {
+                    additional_properties: Some(Box::new(ModelReference::Any)),
+                    ..Default::default()
                 }),
             }),
         }
@@ -260,3 +574,6 @@ impl OpgModel for chrono::NaiveDate {
         ModelReference::Inline(Self::get_schema(cx).apply_params(params))
     }
 }
+
+#[cfg(feature = "serde_json")]
+impl_opg_model!(number(always_inline): serde_json::Number);
@@ -1,4 +1,5 @@
 pub use http;
+pub use serde_json;
 
 pub trait FromStrangeTuple<T> {
     fn extract(self) -> Option<T>;
@@ -28,6 +29,10 @@ macro_rules! describe_type (
     }) => {
         $crate::Model {
             description: $crate::macros::FromStrangeTuple::extract(($($description.to_string(),)?)),
+            example: None,
+            default: None,
+            read_only: false,
+            write_only: false,
             data: $type,
         }
     };
@@ -40,14 +45,19 @@ macro_rules! describe_type (
     }) => {
         $crate::Model {
             description: $crate::macros::FromStrangeTuple::extract(($($description.to_string(),)?)),
+            example: None$(.or($crate::example_value(&$example)))?,
+            default: None,
+            read_only: false,
+            write_only: false,
             data: $crate::ModelData::Single($crate::ModelType {
                 nullable: false,
                 type_description: $crate::ModelTypeDescription::String($crate::ModelString {
                     variants: $crate::macros::FromStrangeTuple::extract(($(vec![$($variants.to_string()),*],)?)),
                     data: $crate::ModelSimple {
                         format: $crate::macros::FromStrangeTuple::extract(($($format.to_string(),)?)),
-                        example: $crate::macros::FromStrangeTuple::extract(($($example.to_string(),)?)),
-                    }
+                        ..Default::default()
+                    },
+                    ..Default::default()
                 })
             })
         }
@@ -60,11 +70,15 @@ macro_rules! describe_type (
     }) => {
         $crate::Model {
             description: $crate::macros::FromStrangeTuple::extract(($($description.to_string(),)?)),
+            example: None$(.or($crate::example_value(&$example)))?,
+            default: None,
+            read_only: false,
+            write_only: false,
             data: $crate::ModelData::Single($crate::ModelType {
                 nullable: false,
                 type_description: $crate::ModelTypeDescription::Number($crate::ModelSimple {
                     format: $crate::macros::FromStrangeTuple::extract(($($format.to_string(),)?)),
-                    example: $crate::macros::FromStrangeTuple::extract(($($example.to_string(),)?)),
+                    ..Default::default()
                 })
             })
         }
@@ -73,15 +87,21 @@ macro_rules! describe_type (
     (integer => {
         $(description: $description:literal)?
         $(format: $format:literal)?
+        $(minimum: $minimum:literal)?
         $(example: $example:literal)?
     }) => {
         $crate::Model {
             description: $crate::macros::FromStrangeTuple::extract(($($description.to_string(),)?)),
+            example: None$(.or($crate::example_value(&$example)))?,
+            default: None,
+            read_only: false,
+            write_only: false,
             data: $crate::ModelData::Single($crate::ModelType {
                 nullable: false,
                 type_description: $crate::ModelTypeDescription::Integer($crate::ModelSimple {
                     format: $crate::macros::FromStrangeTuple::extract(($($format.to_string(),)?)),
-                    example: $crate::macros::FromStrangeTuple::extract(($($example.to_string(),)?)),
+                    minimum: $crate::macros::FromStrangeTuple::extract(($($minimum.into(),)?)),
+                    ..Default::default()
                 })
             })
         }
@@ -92,6 +112,10 @@ macro_rules! describe_type (
     }) => {
         $crate::Model {
             description: $crate::macros::FromStrangeTuple::extract(($($description.to_string(),)?)),
+            example: None,
+            default: None,
+            read_only: false,
+            write_only: false,
             data: $crate::ModelData::Single($crate::ModelType {
                 nullable: false,
                 type_description: $crate::ModelTypeDescription::Boolean
@@ -105,10 +129,15 @@ macro_rules! describe_type (
     }) => {
         $crate::Model {
             description: $crate::macros::FromStrangeTuple::extract(($($description.to_string(),)?)),
+            example: None,
+            default: None,
+            read_only: false,
+            write_only: false,
             data: $crate::ModelData::Single($crate::ModelType {
                 nullable: false,
                 type_description: $crate::ModelTypeDescription::Array($crate::ModelArray {
-                    items: Box::new($crate::describe_type!(@object_property_value $($property_tail)*))
+                    items: Box::new($crate::describe_type!(@object_property_value $($property_tail)*)),
+                    ..Default::default()
                 })
             })
         }
@@ -128,6 +157,10 @@ macro_rules! describe_type (
 
         $crate::Model {
             description: $crate::macros::FromStrangeTuple::extract(($($description.to_string(),)?)),
+            example: None,
+            default: None,
+            read_only: false,
+            write_only: false,
             data: $crate::ModelData::Single($crate::ModelType {
                 nullable: false,
                 type_description: $crate::ModelTypeDescription::Object($crate::ModelObject {
@@ -180,6 +213,14 @@ macro_rules! impl_opg_model (
             fn type_name() -> Option<std::borrow::Cow<'static, str>> {
                 <T as $crate::OpgModel>::type_name()
             }
+
+            #[inline]
+            fn select_reference(cx: &mut $crate::Components, inline: bool, params: &$crate::ContextParams) -> $crate::ModelReference {
+                cx.mention_schema::<T>(inline, &$crate::ContextParams {
+                    nullable: Some(true),
+                    ..params.clone()
+                })
+            }
         }
     };
 
@@ -196,6 +237,11 @@ macro_rules! impl_opg_model (
             fn type_name() -> Option<std::borrow::Cow<'static, str>> {
                 <T as $crate::OpgModel>::type_name()
             }
+
+            #[inline]
+            fn select_reference(cx: &mut $crate::Components, inline: bool, params: &$crate::ContextParams) -> $crate::ModelReference {
+                <T as $crate::OpgModel>::select_reference(cx, inline, params)
+            }
         }
     };
 
@@ -207,16 +253,34 @@ macro_rules! impl_opg_model (
             fn get_schema(cx: &mut $crate::Components) -> $crate::Model {
                 let item_model = $crate::Model {
                     description: None,
+                    example: None,
+                    default: None,
+                    read_only: false,
+                    write_only: false,
                     data: $crate::ModelData::OneOf($crate::ModelOneOf {
                         one_of: vec![
                             $(cx.mention_schema::<$type>(false, &Default::default())),*
                         ],
+                        discriminator: None,
+                        nullable: false,
                     }),
                 };
 
-                $crate::describe_type!(array => {
-                    items: (raw_model => item_model)
-                })
+                $crate::Model {
+                    description: None,
+                    example: None,
+                    default: None,
+                    read_only: false,
+                    write_only: false,
+                    data: $crate::ModelData::Single($crate::ModelType {
+                        nullable: false,
+                        type_description: $crate::ModelTypeDescription::Array($crate::ModelArray {
+                            items: Box::new($crate::ModelReference::Inline(item_model)),
+                            tuple: true,
+                            ..Default::default()
+                        })
+                    })
+                }
             }
 
             #[inline]
@@ -240,10 +304,15 @@ macro_rules! impl_opg_model (
             fn get_schema(cx: &mut $crate::Components) -> $crate::Model {
                 Model {
                     description: None,
+                    example: None,
+                    default: None,
+                    read_only: false,
+                    write_only: false,
                     data: $crate::ModelData::Single($crate::ModelType {
                         nullable: false,
                         type_description: $crate::ModelTypeDescription::Array($crate::ModelArray {
                             items: Box::new(cx.mention_schema::<T>(false, &Default::default())),
+                            ..Default::default()
                         })
                     }),
                 }
@@ -270,6 +339,10 @@ macro_rules! impl_opg_model (
             fn get_schema(cx: &mut $crate::Components) -> $crate::Model {
                 Model {
                     description: None,
+                    example: None,
+                    default: None,
+                    read_only: false,
+                    write_only: false,
                     data: $crate::ModelData::Single($crate::ModelType {
                         nullable: false,
                         type_description: $crate::ModelTypeDescription::Object($crate::ModelObject {
@@ -307,11 +380,12 @@ macro_rules! impl_opg_model (
         }
     };
 
-    ($serialized_type:ident(always_inline$(, $format:literal)?): $($type:tt)+) => {
+    ($serialized_type:ident(always_inline$(, $format:literal)?$(, minimum = $minimum:literal)?): $($type:tt)+) => {
         impl $crate::OpgModel for $($type)+ {
             fn get_schema(_: &mut $crate::Components) -> Model {
                 $crate::describe_type!($serialized_type => {
                     $(format: $format)?
+                    $(minimum: $minimum)?
                 })
             }
 
@@ -349,6 +423,15 @@ macro_rules! describe_api {
     (@opg_info_property description $value:expr) => { Some(($value).to_string()) };
 
 
+    (@opg_property $result:ident dialect $dialect:ident) => {{
+        $result.dialect = $crate::models::OpenApiDialect::$dialect;
+    }};
+
+    (@opg_property $result:ident json_schema_dialect $value:expr) => {{
+        $result.json_schema_dialect = Some(($value).to_string());
+    }};
+
+
     (@opg_property $result:ident tags $($tag:ident$(($description:expr))?),*$(,)?) => {{
         $($result.tags.insert(stringify!($tag).to_owned(), $crate::models::Tag {
             description: $crate::macros::FromStrangeTuple::extract(($(($description).to_string(),)?)),
@@ -356,12 +439,58 @@ macro_rules! describe_api {
     }};
 
 
-    (@opg_property $result:ident servers $($url:tt$(($description:tt))?),*$(,)?) => {{
-        $($result.servers.push($crate::models::Server {
+    (@opg_property $result:ident servers $($servers:tt)*) => {{
+        $crate::describe_api!(@opg_server $result $($servers)*,);
+    }};
+    (@opg_server $result:ident ($url:tt): { $($properties:tt)* }, $($other:tt)*) => {
+        {
+            let mut server = $crate::models::Server {
+                url: $crate::describe_api!(@opg_property_server_literal $url),
+                description: None,
+                variables: std::collections::BTreeMap::new(),
+            };
+            $crate::describe_api!(@opg_server_properties server $($properties)*,);
+            $result.servers.push(server);
+        }
+        $crate::describe_api!(@opg_server $result $($other)*)
+    };
+    (@opg_server $result:ident $url:tt$(($description:tt))?, $($other:tt)*) => {
+        $result.servers.push($crate::models::Server {
             url: $crate::describe_api!(@opg_property_server_literal $url),
             description: $crate::macros::FromStrangeTuple::extract(($($crate::describe_api!(@opg_property_server_literal $description),)?)),
-        }));*;
-    }};
+            variables: std::collections::BTreeMap::new(),
+        });
+        $crate::describe_api!(@opg_server $result $($other)*)
+    };
+    (@opg_server $result:ident $(,)?) => {};
+
+    (@opg_server_properties $context:ident description: $value:expr, $($other:tt)*) => {
+        $context.description = Some(($value).to_string());
+        $crate::describe_api!(@opg_server_properties $context $($other)*)
+    };
+    (@opg_server_properties $context:ident variables: { $($name:tt: { default: $default:expr$(, $($properties:tt)*)? }),*$(,)? }, $($other:tt)*) => {
+        $({
+            let mut variable = $crate::models::ServerVariable {
+                default: ($default).to_string(),
+                ..Default::default()
+            };
+            $crate::describe_api!(@opg_server_variable_properties variable $($($properties)*)?,);
+            $context.variables.insert($crate::describe_api!(@opg_map_key $name), variable);
+        })*
+        $crate::describe_api!(@opg_server_properties $context $($other)*)
+    };
+    (@opg_server_properties $context:ident $(,)?) => {};
+
+    (@opg_server_variable_properties $context:ident enum: [$($variant:expr),*$(,)?], $($other:tt)*) => {
+        $($context.variants.push(($variant).to_string());)*
+        $crate::describe_api!(@opg_server_variable_properties $context $($other)*)
+    };
+    (@opg_server_variable_properties $context:ident description: $value:expr, $($other:tt)*) => {
+        $context.description = Some(($value).to_string());
+        $crate::describe_api!(@opg_server_variable_properties $context $($other)*)
+    };
+    (@opg_server_variable_properties $context:ident $(,)?) => {};
+
     (@opg_property_server_literal $l:literal) => { $l.to_owned() };
     (@opg_property_server_literal $l:ident) => { $l.into() };
 
@@ -413,6 +542,32 @@ macro_rules! describe_api {
         };
         $crate::describe_api!(@opg_security_scheme $result $($other)*)
     };
+    (@opg_security_scheme $result:ident (oauth2 $name:expr): {$($properties:tt)+}, $($other:tt)*) => {
+        {
+            let mut scheme = $crate::models::OAuth2SecurityScheme::default();
+
+            $crate::describe_api!(@opg_security_scheme_oauth2 scheme $($properties)*,);
+
+            $result.components.security_schemes.insert(($name).to_string(), $crate::models::SecurityScheme::OAuth2(scheme));
+        };
+        $crate::describe_api!(@opg_security_scheme $result $($other)*)
+    };
+    (@opg_security_scheme $result:ident (openIdConnect $name:expr): {$($properties:tt)+}, $($other:tt)*) => {
+        {
+            let open_id_connect_url = $crate::models::ParameterNotSpecified;
+            let mut description: Option<String> = None;
+
+            $crate::describe_api!(@opg_security_scheme_open_id_connect open_id_connect_url description $($properties)*,);
+
+            let scheme = $crate::models::OpenIdConnectSecurityScheme {
+                open_id_connect_url,
+                description,
+            };
+
+            $result.components.security_schemes.insert(($name).to_string(), $crate::models::SecurityScheme::OpenIdConnect(scheme));
+        };
+        $crate::describe_api!(@opg_security_scheme $result $($other)*)
+    };
     (@opg_security_scheme $result:ident $(,)?) => {};
 
 
@@ -446,6 +601,51 @@ macro_rules! describe_api {
     (@opg_security_scheme_api_key $parameter_in:ident $name:ident $description:ident $(,)?) => {};
 
 
+    (@opg_security_scheme_oauth2 $context:ident flows: { $($flow:ident: { $($properties:tt)* }),*$(,)? }, $($other:tt)*) => {
+        $({
+            let mut flow = $crate::models::OAuthFlow::default();
+            $crate::describe_api!(@opg_security_scheme_oauth2_flow flow $($properties)*,);
+            $context.flows.$flow = Some(flow);
+        })*
+        $crate::describe_api!(@opg_security_scheme_oauth2 $context $($other)*)
+    };
+    (@opg_security_scheme_oauth2 $context:ident description: $value:expr, $($other:tt)*) => {
+        $context.description = Some(($value).to_string());
+        $crate::describe_api!(@opg_security_scheme_oauth2 $context $($other)*)
+    };
+    (@opg_security_scheme_oauth2 $context:ident $(,)?) => {};
+
+
+    (@opg_security_scheme_oauth2_flow $context:ident authorization_url: $value:expr, $($other:tt)*) => {
+        $context.authorization_url = Some(($value).to_string());
+        $crate::describe_api!(@opg_security_scheme_oauth2_flow $context $($other)*)
+    };
+    (@opg_security_scheme_oauth2_flow $context:ident token_url: $value:expr, $($other:tt)*) => {
+        $context.token_url = Some(($value).to_string());
+        $crate::describe_api!(@opg_security_scheme_oauth2_flow $context $($other)*)
+    };
+    (@opg_security_scheme_oauth2_flow $context:ident refresh_url: $value:expr, $($other:tt)*) => {
+        $context.refresh_url = Some(($value).to_string());
+        $crate::describe_api!(@opg_security_scheme_oauth2_flow $context $($other)*)
+    };
+    (@opg_security_scheme_oauth2_flow $context:ident scopes: { $($scope:tt: $description:expr),*$(,)? }, $($other:tt)*) => {
+        $($context.scopes.insert($crate::describe_api!(@opg_map_key $scope), ($description).to_string());)*
+        $crate::describe_api!(@opg_security_scheme_oauth2_flow $context $($other)*)
+    };
+    (@opg_security_scheme_oauth2_flow $context:ident $(,)?) => {};
+
+
+    (@opg_security_scheme_open_id_connect $url:ident $description:ident open_id_connect_url: $value:expr, $($other:tt)*) => {
+        let $url = ($value).to_string();
+        $crate::describe_api!(@opg_security_scheme_open_id_connect $url $description $($other)*)
+    };
+    (@opg_security_scheme_open_id_connect $url:ident $description:ident description: $value:expr, $($other:tt)*) => {
+        $description = Some(($value).to_string());
+        $crate::describe_api!(@opg_security_scheme_open_id_connect $url $description $($other)*)
+    };
+    (@opg_security_scheme_open_id_connect $url:ident $description:ident $(,)?) => {};
+
+
     (@opg_property $result:ident paths $(($($path_segment:tt)+): {
         $($properties:tt)*
     }),*$(,)?) => {{
@@ -460,6 +660,16 @@ macro_rules! describe_api {
         };)*
     }};
 
+    (@opg_property $result:ident webhooks $($name:tt: {
+        $($properties:tt)*
+    }),*$(,)?) => {{
+        $({
+            let mut context = $crate::models::PathValue::default();
+            $crate::describe_api!(@opg_path_value_properties $result context $($properties)*,);
+            $result.webhooks.insert($crate::describe_api!(@opg_map_key $name), context);
+        };)*
+    }};
+
     (@opg_property_url $path:ident $result:ident $context:ident { / $($rest:tt)* } $($current:tt)+) => {
         $crate::describe_api!(@opg_path_url $path $result $context $($current)*);
         $crate::describe_api!(@opg_property_url $path $result $context { $($rest)* } )
@@ -523,53 +733,54 @@ macro_rules! describe_api {
         $crate::describe_api!(@opg_path_value_operation_properties $result $context $($other)*)
     };
     (@opg_path_value_operation_properties $result:ident $context:ident body: { $($body:tt)* }, $($other:tt)*) => {
-        let mut description = None;
-        let mut required = true;
-        let schema = $crate::models::ParameterNotSpecified;
-        $crate::describe_api!(@opg_path_value_body_properties $result description required schema $($body)*,);
-        $context.with_request_body($crate::models::RequestBody {
-           description: description.or(Some(String::new())),
-           required,
-           schema, // schema must be specified
-        });
+        let mut body = $crate::models::RequestBody {
+            description: Some(String::new()),
+            required: true,
+            content: Default::default(),
+        };
+        let content = $crate::models::ParameterNotSpecified;
+        let direction = $result.components.set_direction(Some($crate::models::SchemaDirection::Request));
+        $crate::describe_api!(@opg_path_value_body_properties $result body content $($body)*,);
+        $result.components.set_direction(direction);
+        let () = content; // schema or content must be specified
+        $context.with_request_body(body);
         $crate::describe_api!(@opg_path_value_operation_properties $result $context $($other)*)
     };
     (@opg_path_value_operation_properties $result:ident $context:ident body: $type:path, $($other:tt)*) => {
-        $context.with_request_body($crate::models::RequestBody {
-           description: Some(String::new()),
-           required: true,
-           schema: $result.components.mention_schema::<$type>(false, &Default::default()),
-        });
+        $crate::describe_api!(@opg_path_value_operation_properties $result $context
+            body: { schema: $type },
+            $($other)*)
+    };
+    (@opg_path_value_operation_properties $result:ident $context:ident $response:literal$(($description:literal))?: { $($properties:tt)* }, $($other:tt)*) => {
+        {
+            let mut response = $crate::models::Response {
+                description: $crate::describe_api!(@opg_response_description $response$(($description))?),
+                headers: std::collections::BTreeMap::new(),
+                content: Default::default(),
+                links: std::collections::BTreeMap::new(),
+            };
+            let direction = $result.components.set_direction(Some($crate::models::SchemaDirection::Response));
+            $crate::describe_api!(@opg_path_value_response_properties $result response $($properties)*,);
+            $result.components.set_direction(direction);
+            $context.responses.insert($response.into(), response);
+        }
         $crate::describe_api!(@opg_path_value_operation_properties $result $context $($other)*)
     };
     (@opg_path_value_operation_properties $result:ident $context:ident $response:literal$(($description:literal))?: None, $($other:tt)*) => {
         $crate::describe_api!(@opg_path_value_operation_properties $result $context
-            $response$(($description))?: { None },
+            $response$(($description))?: {},
             $($other)*)
     };
     (@opg_path_value_operation_properties $result:ident $context:ident $response:literal$(($description:literal))?: (), $($other:tt)*) => {
         $crate::describe_api!(@opg_path_value_operation_properties $result $context
-            $response$(($description))?: { Some($result.components.mention_schema::<()>(false, &Default::default())) },
+            $response$(($description))?: { schema: () },
             $($other)*)
     };
     (@opg_path_value_operation_properties $result:ident $context:ident $response:literal$(($description:literal))?: $type:path, $($other:tt)*) => {
         $crate::describe_api!(@opg_path_value_operation_properties $result $context
-            $response$(($description))?: { Some($result.components.mention_schema::<$type>(false, &Default::default())) },
+            $response$(($description))?: { schema: $type },
             $($other)*)
     };
-    (@opg_path_value_operation_properties $result:ident $context:ident $response:literal$(($description:literal))?: {$($schema:tt)+}, $($other:tt)*) => {
-        $context.responses.insert($response, $crate::models::Response {
-            description: $crate::macros::FromStrangeTuple::extract(($($description.to_owned(),)?)).unwrap_or_else(||
-                $crate::macros::http::StatusCode::from_u16($response)
-                    .ok()
-                    .and_then(|status| status.canonical_reason())
-                    .map(ToString::to_string)
-                    .unwrap_or_else(String::new)
-            ),
-            schema: $($schema)+
-        });
-        $crate::describe_api!(@opg_path_value_operation_properties $result $context $($other)*)
-    };
     (@opg_path_value_operation_properties $result:ident $context:ident $(,)?) => {};
 
 
@@ -585,26 +796,207 @@ macro_rules! describe_api {
 
 
     (@opg_path_value_security_item $result:ident $context:ident $security:literal$([$($role:literal),*])?) => {
-        $context.insert($security.to_owned(), vec![$($($role),*)?])
+        $context.insert($security.to_owned(), vec![$($($role.to_owned()),*)?])
     };
     (@opg_path_value_security_item $result:ident $context:ident $security:ident$([$($role:literal),*])?) => {
-        $context.insert($result.components.mention_security_scheme(stringify!($security).to_owned(), &$security), vec![$($($role),*)?])
+        $context.insert($result.components.mention_security_scheme(stringify!($security).to_owned(), &$security), vec![$($($role.to_owned()),*)?])
     };
 
 
-    (@opg_path_value_body_properties $result:ident $description:ident $required:ident $schema:ident schema: $type:path, $($other:tt)*) => {
-        let $schema = $result.components.mention_schema::<$type>(false, &Default::default());
-        $crate::describe_api!(@opg_path_value_body_properties $result $description $required $schema $($other)*)
+    (@opg_path_value_body_properties $result:ident $context:ident $content:ident schema: (), $($other:tt)*) => {
+        $context.content.insert($crate::DEFAULT_MEDIA_TYPE.to_owned(), $crate::models::MediaType::with_schema(
+            $result.components.mention_schema::<()>(false, &Default::default())
+        ));
+        let $content = ();
+        $crate::describe_api!(@opg_path_value_body_properties $result $context $content $($other)*)
     };
-    (@opg_path_value_body_properties $result:ident $description:ident $required:ident $schema:ident description: $value:literal, $($other:tt)*) => {
-        $description = Some($value.to_owned());
-        $crate::describe_api!(@opg_path_value_body_properties $result $description $required $schema $($other)*)
+    (@opg_path_value_body_properties $result:ident $context:ident $content:ident schema: $type:path, $($other:tt)*) => {
+        $context.content.insert($crate::DEFAULT_MEDIA_TYPE.to_owned(), $crate::models::MediaType::with_schema(
+            $result.components.mention_schema::<$type>(false, &Default::default())
+        ));
+        let $content = ();
+        $crate::describe_api!(@opg_path_value_body_properties $result $context $content $($other)*)
     };
-    (@opg_path_value_body_properties $result:ident $description:ident $required:ident $schema:ident required: $value:literal, $($other:tt)*) => {
-        $required = $value;
-        $crate::describe_api!(@opg_path_value_body_properties $result $description $required $schema $($other)*)
+    (@opg_path_value_body_properties $result:ident $context:ident $content:ident content: { $($items:tt)+ }, $($other:tt)*) => {
+        $crate::describe_api!(@opg_content $result $context $($items)*,);
+        let $content = ();
+        $crate::describe_api!(@opg_path_value_body_properties $result $context $content $($other)*)
     };
-    (@opg_path_value_body_properties $result:ident $description:ident $required:ident $schema:ident $(,)?) => {};
+    (@opg_path_value_body_properties $result:ident $context:ident $content:ident description: $value:literal, $($other:tt)*) => {
+        $context.description = Some($value.to_owned());
+        $crate::describe_api!(@opg_path_value_body_properties $result $context $content $($other)*)
+    };
+    (@opg_path_value_body_properties $result:ident $context:ident $content:ident required: $value:literal, $($other:tt)*) => {
+        $context.required = $value;
+        $crate::describe_api!(@opg_path_value_body_properties $result $context $content $($other)*)
+    };
+    (@opg_path_value_body_properties $result:ident $context:ident $content:ident $(,)?) => {};
+
+
+    (@opg_response_description $response:literal($description:literal)) => { $description.to_owned() };
+    (@opg_response_description $response:literal) => {
+        $crate::macros::http::StatusCode::from_u16($response)
+            .ok()
+            .and_then(|status| status.canonical_reason())
+            .map(ToString::to_string)
+            .unwrap_or_else(String::new)
+    };
+
+
+    (@opg_path_value_response_properties $result:ident $context:ident schema: (), $($other:tt)*) => {
+        $context.content.insert($crate::DEFAULT_MEDIA_TYPE.to_owned(), $crate::models::MediaType::with_schema(
+            $result.components.mention_schema::<()>(false, &Default::default())
+        ));
+        $crate::describe_api!(@opg_path_value_response_properties $result $context $($other)*)
+    };
+    (@opg_path_value_response_properties $result:ident $context:ident schema: $type:path, $($other:tt)*) => {
+        $context.content.insert($crate::DEFAULT_MEDIA_TYPE.to_owned(), $crate::models::MediaType::with_schema(
+            $result.components.mention_schema::<$type>(false, &Default::default())
+        ));
+        $crate::describe_api!(@opg_path_value_response_properties $result $context $($other)*)
+    };
+    (@opg_path_value_response_properties $result:ident $context:ident content: { $($content:tt)+ }, $($other:tt)*) => {
+        $crate::describe_api!(@opg_content $result $context $($content)*,);
+        $crate::describe_api!(@opg_path_value_response_properties $result $context $($other)*)
+    };
+    (@opg_path_value_response_properties $result:ident $context:ident headers: { $($headers:tt)* }, $($other:tt)*) => {
+        $crate::describe_api!(@opg_response_headers $result $context $($headers)*,);
+        $crate::describe_api!(@opg_path_value_response_properties $result $context $($other)*)
+    };
+    (@opg_path_value_response_properties $result:ident $context:ident links: { $($name:tt: { $($properties:tt)* }),*$(,)? }, $($other:tt)*) => {
+        $({
+            let mut link = $crate::models::Link::default();
+            $crate::describe_api!(@opg_link_properties link $($properties)*,);
+            $context.links.insert($crate::describe_api!(@opg_map_key $name), link);
+        })*
+        $crate::describe_api!(@opg_path_value_response_properties $result $context $($other)*)
+    };
+    (@opg_path_value_response_properties $result:ident $context:ident $(,)?) => {};
+
+
+    (@opg_response_headers $result:ident $context:ident $name:tt: { $($properties:tt)* }, $($other:tt)*) => {
+        {
+            let mut header = $crate::models::Header {
+                schema: Some($result.components.mention_schema::<String>(false, &Default::default())),
+                ..Default::default()
+            };
+            $crate::describe_api!(@opg_path_value_parameter_properties $result header $($properties)*,);
+            $context.headers.insert($crate::describe_api!(@opg_map_key $name), header);
+        }
+        $crate::describe_api!(@opg_response_headers $result $context $($other)*)
+    };
+    (@opg_response_headers $result:ident $context:ident $name:tt: $type:path, $($other:tt)*) => {
+        $crate::describe_api!(@opg_response_headers $result $context $name: { schema: $type }, $($other)*)
+    };
+    (@opg_response_headers $result:ident $context:ident $(,)?) => {};
+
+
+    (@opg_link_properties $context:ident operation_id: $value:expr, $($other:tt)*) => {
+        $context.operation_id = Some(($value).to_string());
+        $crate::describe_api!(@opg_link_properties $context $($other)*)
+    };
+    (@opg_link_properties $context:ident operation_ref: $value:expr, $($other:tt)*) => {
+        $context.operation_ref = Some(($value).to_string());
+        $crate::describe_api!(@opg_link_properties $context $($other)*)
+    };
+    (@opg_link_properties $context:ident parameters: { $($name:tt: $value:expr),*$(,)? }, $($other:tt)*) => {
+        $(if let Some(value) = $crate::example_value(&$value) {
+            $context.parameters.insert($crate::describe_api!(@opg_map_key $name), value);
+        })*
+        $crate::describe_api!(@opg_link_properties $context $($other)*)
+    };
+    (@opg_link_properties $context:ident request_body: $value:expr, $($other:tt)*) => {
+        $context.request_body = $crate::example_value(&$value);
+        $crate::describe_api!(@opg_link_properties $context $($other)*)
+    };
+    (@opg_link_properties $context:ident description: $value:expr, $($other:tt)*) => {
+        $context.description = Some(($value).to_string());
+        $crate::describe_api!(@opg_link_properties $context $($other)*)
+    };
+    (@opg_link_properties $context:ident $(,)?) => {};
+
+
+    (@opg_content $result:ident $context:ident $media_type:literal: { $($properties:tt)* }, $($other:tt)*) => {
+        {
+            let mut media_type = $crate::models::MediaType::default();
+            $crate::describe_api!(@opg_media_type_properties $result media_type $($properties)*,);
+            $context.content.insert(($media_type).to_owned(), media_type);
+        }
+        $crate::describe_api!(@opg_content $result $context $($other)*)
+    };
+    (@opg_content $result:ident $context:ident $media_type:literal: $type:path, $($other:tt)*) => {
+        $crate::describe_api!(@opg_content $result $context $media_type: { schema: $type }, $($other)*)
+    };
+    (@opg_content $result:ident $context:ident $(,)?) => {};
+
+
+    (@opg_media_type_properties $result:ident $context:ident schema: $type:path, $($other:tt)*) => {
+        $context.schema = Some($result.components.mention_schema::<$type>(false, &Default::default()));
+        $crate::describe_api!(@opg_media_type_properties $result $context $($other)*)
+    };
+    (@opg_media_type_properties $result:ident $context:ident example: $value:expr, $($other:tt)*) => {
+        $context.example = $crate::example_value(&$value);
+        $crate::describe_api!(@opg_media_type_properties $result $context $($other)*)
+    };
+    (@opg_media_type_properties $result:ident $context:ident examples: { $($name:tt: { $($properties:tt)* }),*$(,)? }, $($other:tt)*) => {
+        $({
+            let mut example = $crate::models::Example::default();
+            $crate::describe_api!(@opg_example_properties example $($properties)*,);
+            $context.examples.insert($crate::describe_api!(@opg_map_key $name), example);
+        })*
+        $crate::describe_api!(@opg_media_type_properties $result $context $($other)*)
+    };
+    (@opg_media_type_properties $result:ident $context:ident encoding: { $($name:tt: { $($properties:tt)* }),*$(,)? }, $($other:tt)*) => {
+        $({
+            let mut encoding = $crate::models::Encoding::default();
+            $crate::describe_api!(@opg_encoding_properties encoding $($properties)*,);
+            $context.encoding.insert($crate::describe_api!(@opg_map_key $name), encoding);
+        })*
+        $crate::describe_api!(@opg_media_type_properties $result $context $($other)*)
+    };
+    (@opg_media_type_properties $result:ident $context:ident $(,)?) => {};
+
+
+    (@opg_example_properties $context:ident summary: $value:expr, $($other:tt)*) => {
+        $context.summary = Some(($value).to_string());
+        $crate::describe_api!(@opg_example_properties $context $($other)*)
+    };
+    (@opg_example_properties $context:ident description: $value:expr, $($other:tt)*) => {
+        $context.description = Some(($value).to_string());
+        $crate::describe_api!(@opg_example_properties $context $($other)*)
+    };
+    (@opg_example_properties $context:ident value: $value:expr, $($other:tt)*) => {
+        $context.value = $crate::example_value(&$value);
+        $crate::describe_api!(@opg_example_properties $context $($other)*)
+    };
+    (@opg_example_properties $context:ident external_value: $value:expr, $($other:tt)*) => {
+        $context.external_value = Some(($value).to_string());
+        $crate::describe_api!(@opg_example_properties $context $($other)*)
+    };
+    (@opg_example_properties $context:ident $(,)?) => {};
+
+
+    (@opg_encoding_properties $context:ident content_type: $value:expr, $($other:tt)*) => {
+        $context.content_type = Some(($value).to_string());
+        $crate::describe_api!(@opg_encoding_properties $context $($other)*)
+    };
+    (@opg_encoding_properties $context:ident style: $value:ident, $($other:tt)*) => {
+        $context.style = Some($crate::models::ParameterStyle::$value);
+        $crate::describe_api!(@opg_encoding_properties $context $($other)*)
+    };
+    (@opg_encoding_properties $context:ident explode: $value:expr, $($other:tt)*) => {
+        $context.explode = Some($value);
+        $crate::describe_api!(@opg_encoding_properties $context $($other)*)
+    };
+    (@opg_encoding_properties $context:ident allow_reserved: $value:expr, $($other:tt)*) => {
+        $context.allow_reserved = $value;
+        $crate::describe_api!(@opg_encoding_properties $context $($other)*)
+    };
+    (@opg_encoding_properties $context:ident $(,)?) => {};
+
+
+    (@opg_map_key $key:literal) => { ($key).to_string() };
+    (@opg_map_key $key:ident) => { stringify!($key).to_owned() };
 
     (@opg_path_value_callbacks $result:ident $context:ident $callback:literal: { $($operations:tt)* }, $($other:tt)*) => {
         let mut callback_object = CallbackObject::default();
@@ -1,47 +1,1294 @@
 use std::borrow::Cow;
-use std::collections::{btree_map::Entry, BTreeMap};
+use std::collections::{btree_map::Entry, BTreeMap, BTreeSet};
 use std::fmt::Write;
+use std::marker::PhantomData;
 
 use either::*;
-use serde::ser::{SerializeMap, SerializeSeq, SerializeStruct};
-use serde::{Serialize, Serializer};
+use indexmap::IndexMap;
+use serde::de::{self, IgnoredAny, MapAccess, Visitor};
+use serde::ser::{self, SerializeMap, SerializeSeq, SerializeStruct};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use serde_json::{json, Value};
 
 /// OpenAPI Object
 ///
 /// [specification](https://swagger.io/specification/#openapi-object)
-#[derive(Debug, Clone, Default, Serialize)]
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
 pub struct Opg {
     /// Semantic version number of the OpenAPI Specification version
     pub openapi: OpenApiVersion,
 
-    /// Provides metadata about the API
-    pub info: Info,
+    /// Provides metadata about the API
+    pub info: Info,
+
+    /// The default value for the `$schema` keyword within schemas.
+    ///
+    /// Only for OpenAPI 3.1, `JSON_SCHEMA_DIALECT` is used by default
+    pub json_schema_dialect: Option<String>,
+
+    /// A list of tags used by the specification with additional metadata
+    #[serde(deserialize_with = "deserialize_tags")]
+    pub tags: BTreeMap<String, Tag>,
+
+    /// An array of Server Objects, which provide connectivity information to a target server
+    pub servers: Vec<Server>,
+
+    /// The available paths and operations for the API
+    #[serde(deserialize_with = "deserialize_ordered_entries")]
+    pub paths: Vec<(Path, PathValue)>,
+
+    /// The incoming webhooks that may be received as part of this API.
+    ///
+    /// Only for OpenAPI 3.1, skipped in OpenAPI 3.0 output
+    pub webhooks: BTreeMap<String, PathValue>,
+
+    /// An element to hold various schemas for the specification
+    pub components: Components,
+
+    /// Specification version used for serialization
+    #[serde(skip)]
+    pub dialect: OpenApiDialect,
+}
+
+impl Serialize for Opg {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        #[derive(Serialize)]
+        #[serde(rename_all = "camelCase")]
+        struct OpgDocument<'a> {
+            openapi: &'a str,
+            info: &'a Info,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            json_schema_dialect: Option<&'a str>,
+            #[serde(
+                skip_serializing_if = "BTreeMap::is_empty",
+                serialize_with = "serialize_tags"
+            )]
+            tags: &'a BTreeMap<String, Tag>,
+            #[serde(skip_serializing_if = "<[_]>::is_empty")]
+            servers: &'a [Server],
+            #[serde(
+                serialize_with = "serialize_ordered_entries",
+                skip_serializing_if = "<[_]>::is_empty"
+            )]
+            paths: &'a [(Path, PathValue)],
+            #[serde(skip_serializing_if = "Option::is_none")]
+            webhooks: Option<&'a BTreeMap<String, PathValue>>,
+            components: &'a Components,
+        }
+
+        // tuples are emitted with `prefixItems` in JSON Schema 2020-12
+        let with_prefix_items;
+        let source = match self.dialect {
+            OpenApiDialect::OpenApi30 => self,
+            OpenApiDialect::OpenApi31 => {
+                let mut source = self.clone();
+                source.for_each_model_mut(&mut Model::use_prefix_items);
+                with_prefix_items = source;
+                &with_prefix_items
+            }
+        };
+
+        let mut document = OpgDocument {
+            openapi: &source.openapi.0,
+            info: &source.info,
+            json_schema_dialect: None,
+            tags: &source.tags,
+            servers: &source.servers,
+            paths: &source.paths,
+            webhooks: None,
+            components: &source.components,
+        };
+
+        match self.dialect {
+            OpenApiDialect::OpenApi30 => document.serialize(serializer),
+            OpenApiDialect::OpenApi31 => {
+                if self.openapi.0.starts_with("3.0") {
+                    document.openapi = crate::OPENAPI_31_VERSION;
+                }
+                document.json_schema_dialect = Some(
+                    self.json_schema_dialect
+                        .as_deref()
+                        .unwrap_or(crate::JSON_SCHEMA_DIALECT),
+                );
+                document.webhooks = Some(&source.webhooks).filter(|webhooks| !webhooks.is_empty());
+
+                let mut document = serde_json::to_value(&document).map_err(ser::Error::custom)?;
+                convert_document_to_openapi_31(&mut document);
+                document.serialize(serializer)
+            }
+        }
+    }
+}
+
+/// Specification version of the serialized document
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq)]
+pub enum OpenApiDialect {
+    /// OpenAPI 3.0 with its own subset of JSON Schema
+    #[default]
+    OpenApi30,
+
+    /// OpenAPI 3.1 with JSON Schema 2020-12
+    OpenApi31,
+}
+
+/// Convert OpenAPI 3.0 document into OpenAPI 3.1
+fn convert_document_to_openapi_31(document: &mut Value) {
+    for_each_value(document.get_mut("paths"), convert_path_item_to_openapi_31);
+    for_each_value(
+        document.get_mut("webhooks"),
+        convert_path_item_to_openapi_31,
+    );
+    for_each_value(
+        document.pointer_mut("/components/schemas"),
+        convert_schema_to_openapi_31,
+    );
+}
+
+fn convert_path_item_to_openapi_31(path_item: &mut Value) {
+    convert_parameters_to_openapi_31(path_item.get_mut("parameters"));
+
+    let path_item = match path_item.as_object_mut() {
+        Some(path_item) => path_item,
+        None => return,
+    };
+
+    for (method, operation) in path_item.iter_mut() {
+        if HttpMethod::from_str(method).is_none() {
+            continue;
+        }
+
+        convert_parameters_to_openapi_31(operation.get_mut("parameters"));
+        convert_content_to_openapi_31(operation.pointer_mut("/requestBody/content"));
+        for_each_value(operation.get_mut("responses"), |response| {
+            convert_content_to_openapi_31(response.get_mut("content"));
+            for_each_value(response.get_mut("headers"), |header| {
+                for_each_schema(header, convert_schema_to_openapi_31)
+            });
+        });
+        for_each_value(operation.get_mut("callbacks"), |callback| {
+            for_each_value(Some(callback), convert_path_item_to_openapi_31)
+        });
+    }
+}
+
+fn convert_parameters_to_openapi_31(parameters: Option<&mut Value>) {
+    if let Some(Value::Array(parameters)) = parameters {
+        parameters
+            .iter_mut()
+            .for_each(|parameter| for_each_schema(parameter, convert_schema_to_openapi_31));
+    }
+}
+
+fn convert_content_to_openapi_31(content: Option<&mut Value>) {
+    for_each_value(content, |media_type| {
+        for_each_schema(media_type, convert_schema_to_openapi_31)
+    });
+}
+
+/// Convert OpenAPI 3.0 schema into JSON Schema 2020-12
+///
+/// Tuples are expected to be already serialized with `prefixItems`, see [`ModelArray::prefix_items`]
+pub(crate) fn convert_schema_to_openapi_31(schema: &mut Value) {
+    let schema = match schema.as_object_mut() {
+        Some(schema) => schema,
+        None => return,
+    };
+
+    if let Some(items) = schema.get_mut("items") {
+        convert_schema_to_openapi_31(items);
+    }
+    if let Some(additional_properties) = schema.get_mut("additionalProperties") {
+        convert_schema_to_openapi_31(additional_properties);
+    }
+    for_each_value(schema.get_mut("properties"), convert_schema_to_openapi_31);
+    for key in &["oneOf", "allOf", "anyOf", "prefixItems"] {
+        if let Some(Value::Array(items)) = schema.get_mut(*key) {
+            items.iter_mut().for_each(convert_schema_to_openapi_31);
+        }
+    }
+
+    // `$ref` wrapped into `allOf` to keep sibling keywords
+    let single_link = match schema.get("allOf") {
+        Some(Value::Array(all_of)) if all_of.len() == 1 && !schema.contains_key("type") => all_of
+            [0]
+        .as_object()
+        .filter(|item| item.len() == 1)
+        .and_then(|item| item.get("$ref"))
+        .cloned(),
+        _ => None,
+    };
+    if let Some(link) = single_link {
+        schema.shift_remove("allOf");
+        schema.insert("$ref".to_owned(), link);
+    }
+
+    let is_null = schema.get("nullable") == Some(&Value::Bool(true))
+        && !schema.contains_key("type")
+        && schema.get("enum") == Some(&Value::Array(vec![Value::Null]));
+    if is_null {
+        schema.shift_remove("nullable");
+        schema.shift_remove("enum");
+        schema.insert("type".to_owned(), "null".into());
+    }
+
+    if let Some(Value::Bool(nullable)) = schema.shift_remove("nullable") {
+        if nullable {
+            match schema.get_mut("type") {
+                Some(Value::String(type_name)) => {
+                    let type_name = std::mem::take(type_name);
+                    schema.insert("type".to_owned(), vec![type_name, "null".to_owned()].into());
+                }
+                _ => match schema
+                    .iter_mut()
+                    .find(|(key, _)| *key == "oneOf" || *key == "anyOf")
+                    .map(|(_, items)| items)
+                {
+                    Some(Value::Array(items)) => items.push(json!({ "type": "null" })),
+                    _ => {
+                        let link = schema
+                            .shift_remove("$ref")
+                            .map(|link| json!({ "$ref": link }));
+                        let all_of = schema
+                            .shift_remove("allOf")
+                            .map(|all_of| json!({ "allOf": all_of }));
+                        let any_of = link
+                            .into_iter()
+                            .chain(all_of)
+                            .chain(Some(json!({ "type": "null" })));
+                        schema.insert("anyOf".to_owned(), Value::Array(any_of.collect()));
+                    }
+                },
+            }
+
+            // `null` must be allowed by the enum as well
+            if let Some(Value::Array(variants)) = schema.get_mut("enum") {
+                variants.push(Value::Null);
+            }
+        }
+    }
+
+    if let Some(example) = schema.shift_remove("example") {
+        schema.insert("examples".to_owned(), Value::Array(vec![example]));
+    }
+
+    if let Some(Value::Array(variants)) = schema.get_mut("enum") {
+        if variants.len() == 1 {
+            let value = variants.remove(0);
+            schema.shift_remove("enum");
+            schema.insert("const".to_owned(), value);
+        }
+    }
+
+    for (exclusive, bound) in &[
+        ("exclusiveMinimum", "minimum"),
+        ("exclusiveMaximum", "maximum"),
+    ] {
+        if let Some(Value::Bool(exclusive_value)) = schema.shift_remove(*exclusive) {
+            if exclusive_value {
+                if let Some(bound_value) = schema.shift_remove(*bound) {
+                    schema.insert((*exclusive).to_owned(), bound_value);
+                }
+            }
+        }
+    }
+}
+
+fn for_each_value<F>(map: Option<&mut Value>, f: F)
+where
+    F: FnMut(&mut Value),
+{
+    if let Some(Value::Object(map)) = map {
+        map.values_mut().for_each(f);
+    }
+}
+
+fn for_each_schema<F>(container: &mut Value, mut f: F)
+where
+    F: FnMut(&mut Value),
+{
+    if let Some(schema) = container.get_mut("schema") {
+        f(schema);
+    }
+}
+
+#[cfg(feature = "serde_json")]
+impl Opg {
+    /// Serialize the document into a pretty printed JSON string
+    pub fn to_json_string_pretty(&self) -> serde_json::Result<String> {
+        serde_json::to_string_pretty(self)
+    }
+
+    /// Serialize the document into a JSON value for further processing
+    pub fn to_json_value(&self) -> serde_json::Result<serde_json::Value> {
+        serde_json::to_value(self)
+    }
+}
+
+impl Opg {
+    /// Check the whole document and report every found problem
+    ///
+    /// Returns an empty list if the document is valid
+    pub fn validate(&self) -> Vec<Diagnostic> {
+        let mut cx = ValidationContext {
+            document: self,
+            operation_ids: Default::default(),
+            diagnostics: Vec::new(),
+        };
+
+        for (index, server) in self.servers.iter().enumerate() {
+            cx.validate_server(server, &format!("/servers/{}", index));
+        }
+
+        cx.validate_paths(&self.paths, "/paths", true);
+
+        for (name, webhook) in &self.webhooks {
+            let location = format!("/webhooks/{}", escape_pointer(name));
+            cx.validate_parameters(
+                &webhook.parameters,
+                &format!("{}/parameters", location),
+                None,
+            );
+            for (method, operation) in &webhook.operations {
+                cx.validate_operation(
+                    operation,
+                    &format!("{}/{}", location, method.as_str()),
+                    None,
+                );
+            }
+        }
+
+        for (name, model) in &self.components.schemas {
+            cx.validate_model(
+                model,
+                &format!("/components/schemas/{}", escape_pointer(name)),
+            );
+        }
+
+        for name in &self.components.name_collisions {
+            cx.report(
+                format!("/components/schemas/{}", escape_pointer(name)),
+                DiagnosticKind::SchemaNameCollision(name.clone()),
+            );
+        }
+
+        for (name, security_scheme) in &self.components.security_schemes {
+            if let SecurityScheme::OAuth2(oauth2) = security_scheme {
+                cx.validate_oauth_flows(
+                    &oauth2.flows,
+                    &format!("/components/securitySchemes/{}/flows", escape_pointer(name)),
+                );
+            }
+        }
+
+        cx.diagnostics
+    }
+
+    /// Merge other document into this one
+    ///
+    /// Paths, tags, servers, schemas and security schemes are combined. Info of this document
+    /// is preserved. Components with the same name and equal content are not considered as
+    /// conflicts, other name conflicts are resolved according to the `policy`. Templated paths
+    /// which differ only in parameter names are conflicts as well.
+    ///
+    /// Nothing is changed if an error is returned.
+    pub fn merge(&mut self, mut other: Opg, policy: &MergePolicy) -> Result<(), OpgMergeError> {
+        let renames = ComponentRenames {
+            schemas: resolve_name_conflicts(
+                &self.components.schemas,
+                &other.components.schemas,
+                policy,
+                Model::rename_links,
+            )
+            .map_err(OpgMergeError::SchemaConflict)?,
+            security_schemes: resolve_name_conflicts(
+                &self.components.security_schemes,
+                &other.components.security_schemes,
+                policy,
+                |_, _| {},
+            )
+            .map_err(OpgMergeError::SecuritySchemeConflict)?,
+        };
+
+        if !matches!(policy, MergePolicy::KeepFirst) {
+            for (path, path_value) in &other.paths {
+                // `/users/{id}` and `/users/{userId}` are considered identical
+                if let Some((existing, _)) = self.paths.iter().find(|(existing, _)| {
+                    existing != path && existing.template() == path.template()
+                }) {
+                    return Err(OpgMergeError::PathConflict {
+                        path: path.to_string(),
+                        existing: existing.to_string(),
+                    });
+                }
+
+                let existing = match self.paths.iter().find(|(existing, _)| existing == path) {
+                    Some((_, existing)) => existing,
+                    None => continue,
+                };
+
+                if let Some(method) = path_value.find_common_method(existing) {
+                    return Err(OpgMergeError::OperationConflict {
+                        path: path.to_string(),
+                        method: method.clone(),
+                    });
+                }
+            }
+
+            for (name, webhook) in &other.webhooks {
+                if let Some(method) = self
+                    .webhooks
+                    .get(name)
+                    .and_then(|existing| webhook.find_common_method(existing))
+                {
+                    return Err(OpgMergeError::WebhookConflict {
+                        name: name.clone(),
+                        method: method.clone(),
+                    });
+                }
+            }
+        }
+
+        other.rename_components(&renames);
+
+        for (path, path_value) in other.paths {
+            let existing = match self
+                .paths
+                .iter_mut()
+                .find(|(existing, _)| existing.template() == path.template())
+            {
+                Some((existing_path, existing)) if existing_path == &path => existing,
+                // templated path with different parameter names is kept only with `KeepFirst`
+                Some(_) => continue,
+                None => {
+                    self.paths.push((path, path_value));
+                    continue;
+                }
+            };
+            existing.merge(path_value);
+        }
+
+        for (name, webhook) in other.webhooks {
+            match self.webhooks.entry(name) {
+                Entry::Vacant(entry) => {
+                    entry.insert(webhook);
+                }
+                Entry::Occupied(mut entry) => entry.get_mut().merge(webhook),
+            }
+        }
+
+        for (name, tag) in other.tags {
+            let existing = self.tags.entry(name).or_default();
+            if existing.description.is_none() {
+                existing.description = tag.description;
+            }
+        }
+
+        for server in other.servers {
+            if !self
+                .servers
+                .iter()
+                .any(|existing| existing.url == server.url)
+            {
+                self.servers.push(server);
+            }
+        }
+
+        for (name, model) in other.components.schemas {
+            self.components.schemas.entry(name).or_insert(model);
+        }
+        self.components
+            .name_collisions
+            .extend(other.components.name_collisions);
+
+        for (name, security_scheme) in other.components.security_schemes {
+            self.components
+                .security_schemes
+                .entry(name)
+                .or_insert(security_scheme);
+        }
+
+        Ok(())
+    }
+
+    /// Rename components and all references to them
+    fn rename_components(&mut self, renames: &ComponentRenames) {
+        if renames.schemas.is_empty() && renames.security_schemes.is_empty() {
+            return;
+        }
+
+        rename_keys(&mut self.components.schemas, &renames.schemas);
+        rename_keys(
+            &mut self.components.security_schemes,
+            &renames.security_schemes,
+        );
+
+        self.components
+            .schemas
+            .values_mut()
+            .for_each(|model| model.rename_links(&renames.schemas));
+
+        self.paths
+            .iter_mut()
+            .map(|(_, path_value)| path_value)
+            .chain(self.webhooks.values_mut())
+            .for_each(|path_value| path_value.rename_components(renames));
+    }
+
+    /// Visit all models of this document, including inline ones
+    fn for_each_model_mut<F>(&mut self, f: &mut F)
+    where
+        F: FnMut(&mut Model),
+    {
+        self.components
+            .schemas
+            .values_mut()
+            .for_each(|model| model.for_each_model_mut(f));
+
+        self.paths
+            .iter_mut()
+            .map(|(_, path_value)| path_value)
+            .chain(self.webhooks.values_mut())
+            .for_each(|path_value| path_value.for_each_model_mut(f));
+    }
+}
+
+/// Policy of resolving component name conflicts while merging documents
+#[derive(Debug, Clone)]
+pub enum MergePolicy {
+    /// Fail on the first conflict
+    Error,
+
+    /// Rename conflicting components of the merged document by adding specified prefix
+    RenameWithPrefix(String),
+
+    /// Keep components and operations of the original document
+    KeepFirst,
+}
+
+/// Documents merge error
+#[derive(Debug, Clone)]
+pub enum OpgMergeError {
+    /// Different schemas with the same name
+    SchemaConflict(String),
+
+    /// Different security schemes with the same name
+    SecuritySchemeConflict(String),
+
+    /// Operation with the same path and method
+    OperationConflict { path: String, method: HttpMethod },
+
+    /// Templated paths which differ only in parameter names
+    PathConflict { path: String, existing: String },
+
+    /// Webhook operation with the same name and method
+    WebhookConflict { name: String, method: HttpMethod },
+}
+
+impl std::fmt::Display for OpgMergeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            OpgMergeError::SchemaConflict(name) => {
+                write!(f, "schema `{}` is already defined", name)
+            }
+            OpgMergeError::SecuritySchemeConflict(name) => {
+                write!(f, "security scheme `{}` is already defined", name)
+            }
+            OpgMergeError::OperationConflict { path, method } => write!(
+                f,
+                "operation `{} {}` is already defined",
+                method.as_str(),
+                path
+            ),
+            OpgMergeError::PathConflict { path, existing } => {
+                write!(f, "path `{}` is identical to `{}`", path, existing)
+            }
+            OpgMergeError::WebhookConflict { name, method } => write!(
+                f,
+                "webhook operation `{} {}` is already defined",
+                method.as_str(),
+                name
+            ),
+        }
+    }
+}
+
+impl std::error::Error for OpgMergeError {}
+
+/// Component renames of the merged document
+struct ComponentRenames {
+    schemas: BTreeMap<String, String>,
+    security_schemes: BTreeMap<String, String>,
+}
+
+/// Find conflicting names and resolve them according to the policy
+///
+/// Returns renames for the `other` components or the first conflicting name
+fn resolve_name_conflicts<T, F>(
+    existing: &BTreeMap<String, T>,
+    other: &BTreeMap<String, T>,
+    policy: &MergePolicy,
+    rename_links: F,
+) -> Result<BTreeMap<String, String>, String>
+where
+    T: Serialize + Clone,
+    F: Fn(&mut T, &BTreeMap<String, String>),
+{
+    let find_conflicts = |renames: &BTreeMap<String, String>| {
+        other
+            .iter()
+            .filter(|(name, item)| match existing.get(*name) {
+                Some(existing_item) => {
+                    let mut item = (*item).clone();
+                    rename_links(&mut item, renames);
+                    serde_json::to_value(existing_item).ok() != serde_json::to_value(&item).ok()
+                }
+                None => false,
+            })
+            .map(|(name, _)| name.clone())
+            .collect::<Vec<_>>()
+    };
+
+    let prefix = match policy {
+        MergePolicy::Error => {
+            return match find_conflicts(&BTreeMap::new()).into_iter().next() {
+                Some(name) => Err(name),
+                None => Ok(BTreeMap::new()),
+            }
+        }
+        MergePolicy::RenameWithPrefix(prefix) => prefix,
+        MergePolicy::KeepFirst => return Ok(BTreeMap::new()),
+    };
+
+    // renaming a component changes links to it, so conflicts are searched
+    // again with renamed links until they stop changing
+    let mut renames = BTreeMap::new();
+    let mut all_renames = BTreeMap::new();
+    let mut converged = false;
+    for _ in 0..=other.len() {
+        let conflicts = find_conflicts(&renames)
+            .into_iter()
+            .map(|name| {
+                let new_name = format!("{}{}", prefix, name);
+                (name, new_name)
+            })
+            .collect::<BTreeMap<_, _>>();
+
+        all_renames.extend(conflicts.clone());
+        if conflicts == renames {
+            converged = true;
+            break;
+        }
+        renames = conflicts;
+    }
+
+    // renaming more components than necessary is still correct
+    if !converged {
+        renames = all_renames;
+    }
+
+    match renames
+        .values()
+        .find(|new_name| existing.contains_key(*new_name) || other.contains_key(*new_name))
+    {
+        Some(new_name) => Err(new_name.clone()),
+        None => Ok(renames),
+    }
+}
+
+fn rename_keys<T>(map: &mut BTreeMap<String, T>, renames: &BTreeMap<String, String>) {
+    for (name, new_name) in renames {
+        if let Some(item) = map.remove(name) {
+            map.insert(new_name.clone(), item);
+        }
+    }
+}
+
+/// Document validation problem
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    /// JSON pointer to the invalid part of the document
+    pub location: String,
+
+    /// Problem description
+    pub kind: DiagnosticKind,
+}
+
+impl std::fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.location, self.kind)
+    }
+}
+
+/// Kind of document validation problem
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DiagnosticKind {
+    /// Path template contains a parameter which is not declared
+    UndeclaredPathParameter(String),
+
+    /// Path parameter is not used in path template
+    UnknownPathParameter(String),
+
+    /// Path parameter is not marked as required
+    OptionalPathParameter(String),
+
+    /// Operation id is used by several operations
+    DuplicateOperationId(String),
+
+    /// Operation tag is not declared in document tags
+    UndeclaredTag(String),
+
+    /// Security requirement refers to an undeclared security scheme
+    UndeclaredSecurityScheme(String),
+
+    /// Security requirement refers to a scope, which is not declared in OAuth2 flows
+    UndeclaredScope {
+        security_scheme: String,
+        scope: String,
+    },
+
+    /// Path template is equal to some previous path template (ignoring parameter names)
+    DuplicatePath(String),
+
+    /// Schema reference refers to a missing schema
+    DanglingReference(String),
+
+    /// String enum without variants
+    EmptyEnum,
+
+    /// String enum variant is specified several times
+    DuplicateEnumVariant(String),
+
+    /// Data type format is not applicable to the type
+    InvalidFormat {
+        type_name: &'static str,
+        format: String,
+    },
+
+    /// Server url contains an opening brace without the closing one
+    UnclosedServerVariable,
+
+    /// Server url contains a variable which is not declared
+    UndeclaredServerVariable(String),
+
+    /// Default value of the server variable is not one of its variants
+    InvalidServerVariableDefault(String),
+
+    /// Schema name is generated for several types or tagged enum variants
+    SchemaNameCollision(String),
+
+    /// OAuth2 flow doesn't specify the URL required for it
+    MissingOAuthFlowUrl {
+        flow: &'static str,
+        url: &'static str,
+    },
+}
+
+impl std::fmt::Display for DiagnosticKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DiagnosticKind::UndeclaredPathParameter(name) => {
+                write!(f, "path parameter `{}` is not declared", name)
+            }
+            DiagnosticKind::UnknownPathParameter(name) => {
+                write!(f, "path parameter `{}` is not used in path", name)
+            }
+            DiagnosticKind::OptionalPathParameter(name) => {
+                write!(f, "path parameter `{}` must be required", name)
+            }
+            DiagnosticKind::DuplicateOperationId(id) => {
+                write!(f, "operation id `{}` is already used", id)
+            }
+            DiagnosticKind::UndeclaredTag(tag) => write!(f, "tag `{}` is not declared", tag),
+            DiagnosticKind::UndeclaredSecurityScheme(name) => {
+                write!(f, "security scheme `{}` is not declared", name)
+            }
+            DiagnosticKind::UndeclaredScope {
+                security_scheme,
+                scope,
+            } => write!(
+                f,
+                "scope `{}` is not declared in security scheme `{}`",
+                scope, security_scheme
+            ),
+            DiagnosticKind::DuplicatePath(path) => write!(f, "path `{}` is already defined", path),
+            DiagnosticKind::DanglingReference(link) => {
+                write!(f, "schema `{}` is not defined", link)
+            }
+            DiagnosticKind::EmptyEnum => f.write_str("enum must have at least one variant"),
+            DiagnosticKind::DuplicateEnumVariant(variant) => {
+                write!(f, "enum variant `{}` is duplicated", variant)
+            }
+            DiagnosticKind::InvalidFormat { type_name, format } => {
+                write!(f, "format `{}` is not applicable to {}", format, type_name)
+            }
+            DiagnosticKind::UnclosedServerVariable => f.write_str("server variable is not closed"),
+            DiagnosticKind::UndeclaredServerVariable(name) => {
+                write!(f, "server variable `{}` is not declared", name)
+            }
+            DiagnosticKind::InvalidServerVariableDefault(name) => write!(
+                f,
+                "default value of server variable `{}` is not one of its variants",
+                name
+            ),
+            DiagnosticKind::SchemaNameCollision(name) => {
+                write!(f, "schema name `{}` is used by several types", name)
+            }
+            DiagnosticKind::MissingOAuthFlowUrl { flow, url } => {
+                write!(f, "`{}` flow requires `{}`", flow, url)
+            }
+        }
+    }
+}
+
+struct ValidationContext<'a> {
+    document: &'a Opg,
+    operation_ids: std::collections::HashSet<&'a str>,
+    diagnostics: Vec<Diagnostic>,
+}
+
+impl<'a> ValidationContext<'a> {
+    fn report(&mut self, location: String, kind: DiagnosticKind) {
+        self.diagnostics.push(Diagnostic { location, kind });
+    }
+
+    fn validate_server(&mut self, server: &Server, location: &str) {
+        let mut rest = server.url.as_str();
+        while let Some(start) = rest.find('{') {
+            let end = match rest[start..].find('}') {
+                Some(end) => end,
+                None => {
+                    self.report(
+                        format!("{}/url", location),
+                        DiagnosticKind::UnclosedServerVariable,
+                    );
+                    break;
+                }
+            };
+
+            let name = &rest[start + 1..start + end];
+            if !server.variables.contains_key(name) {
+                self.report(
+                    format!("{}/url", location),
+                    DiagnosticKind::UndeclaredServerVariable(name.to_owned()),
+                );
+            }
+
+            rest = &rest[start + end + 1..];
+        }
+
+        for (name, variable) in &server.variables {
+            if !variable.variants.is_empty() && !variable.variants.contains(&variable.default) {
+                self.report(
+                    format!("{}/variables/{}/default", location, escape_pointer(name)),
+                    DiagnosticKind::InvalidServerVariableDefault(name.clone()),
+                );
+            }
+        }
+    }
+
+    fn validate_oauth_flows(&mut self, flows: &OAuthFlows, location: &str) {
+        let flows = [
+            (&flows.implicit, "implicit", true, false),
+            (&flows.password, "password", false, true),
+            (&flows.client_credentials, "clientCredentials", false, true),
+            (&flows.authorization_code, "authorizationCode", true, true),
+        ];
+
+        for (flow, flow_name, authorization_url, token_url) in flows {
+            let flow = match flow {
+                Some(flow) => flow,
+                None => continue,
+            };
+
+            let required_urls = [
+                (
+                    authorization_url,
+                    &flow.authorization_url,
+                    "authorizationUrl",
+                ),
+                (token_url, &flow.token_url, "tokenUrl"),
+            ];
+            for (required, value, url) in required_urls {
+                if required && value.is_none() {
+                    self.report(
+                        format!("{}/{}/{}", location, flow_name, url),
+                        DiagnosticKind::MissingOAuthFlowUrl {
+                            flow: flow_name,
+                            url,
+                        },
+                    );
+                }
+            }
+        }
+    }
+
+    fn validate_paths(&mut self, paths: &'a [(Path, PathValue)], location: &str, templates: bool) {
+        let mut known_paths = Vec::new();
+
+        for (path, path_value) in paths {
+            let path_location = format!("{}/{}", location, escape_pointer(&path.to_string()));
+
+            // `/pets/{id}` and `/pets/{petId}` are considered identical
+            let template = path.template();
+            if known_paths.contains(&template) {
+                self.report(
+                    path_location.clone(),
+                    DiagnosticKind::DuplicatePath(path.to_string()),
+                );
+            } else {
+                known_paths.push(template);
+            }
+
+            let template_parameters = path
+                .0
+                .iter()
+                .filter_map(|element| match element {
+                    PathElement::Parameter(name) => Some(name.as_str()),
+                    PathElement::Path(_) => None,
+                })
+                .collect::<Vec<_>>();
+            let template = Some(template_parameters.as_slice()).filter(|_| templates);
+
+            self.validate_parameters(
+                &path_value.parameters,
+                &format!("{}/parameters", path_location),
+                template,
+            );
+
+            if templates && path_value.operations.is_empty() {
+                self.validate_template(
+                    &template_parameters,
+                    &path_value.parameters,
+                    None,
+                    &path_location,
+                );
+            }
+
+            for (method, operation) in &path_value.operations {
+                let operation_location = format!("{}/{}", path_location, method.as_str());
+
+                if templates {
+                    self.validate_template(
+                        &template_parameters,
+                        &path_value.parameters,
+                        Some(&operation.parameters),
+                        &operation_location,
+                    );
+                }
+
+                self.validate_operation(operation, &operation_location, template);
+            }
+        }
+    }
+
+    fn validate_template(
+        &mut self,
+        template_parameters: &[&str],
+        path_parameters: &BTreeMap<String, OperationParameter>,
+        operation_parameters: Option<&BTreeMap<String, OperationParameter>>,
+        location: &str,
+    ) {
+        let is_declared = |parameters: &BTreeMap<String, OperationParameter>, name: &str| {
+            matches!(
+                parameters.get(name),
+                Some(OperationParameter {
+                    parameter_in: ParameterIn::Path,
+                    ..
+                })
+            )
+        };
+
+        for name in template_parameters {
+            if !is_declared(path_parameters, name)
+                && !operation_parameters.is_some_and(|parameters| is_declared(parameters, name))
+            {
+                self.report(
+                    location.to_owned(),
+                    DiagnosticKind::UndeclaredPathParameter((*name).to_owned()),
+                );
+            }
+        }
+    }
+
+    fn validate_parameters(
+        &mut self,
+        parameters: &BTreeMap<String, OperationParameter>,
+        location: &str,
+        template_parameters: Option<&[&str]>,
+    ) {
+        for (index, (name, parameter)) in parameters.iter().enumerate() {
+            let parameter_location = format!("{}/{}", location, index);
+
+            if let ParameterIn::Path = parameter.parameter_in {
+                if !parameter.required {
+                    self.report(
+                        parameter_location.clone(),
+                        DiagnosticKind::OptionalPathParameter(name.clone()),
+                    );
+                }
+                if matches!(template_parameters, Some(template) if !template.contains(&name.as_str()))
+                {
+                    self.report(
+                        parameter_location.clone(),
+                        DiagnosticKind::UnknownPathParameter(name.clone()),
+                    );
+                }
+            }
+
+            if let Some(schema) = &parameter.schema {
+                self.validate_reference(schema, &format!("{}/schema", parameter_location));
+            }
+        }
+    }
+
+    fn validate_operation(
+        &mut self,
+        operation: &'a Operation,
+        location: &str,
+        template_parameters: Option<&[&str]>,
+    ) {
+        if let Some(operation_id) = &operation.operation_id {
+            if !self.operation_ids.insert(operation_id) {
+                self.report(
+                    format!("{}/operationId", location),
+                    DiagnosticKind::DuplicateOperationId(operation_id.clone()),
+                );
+            }
+        }
+
+        for (index, tag) in operation.tags.iter().enumerate() {
+            if !self.document.tags.contains_key(tag) {
+                self.report(
+                    format!("{}/tags/{}", location, index),
+                    DiagnosticKind::UndeclaredTag(tag.clone()),
+                );
+            }
+        }
+
+        for (index, requirement) in operation.security.iter().enumerate() {
+            for (name, scopes) in requirement {
+                let requirement_location =
+                    format!("{}/security/{}/{}", location, index, escape_pointer(name));
+
+                match self.document.components.security_schemes.get(name) {
+                    None => self.report(
+                        requirement_location,
+                        DiagnosticKind::UndeclaredSecurityScheme(name.clone()),
+                    ),
+                    Some(SecurityScheme::OAuth2(scheme)) => {
+                        for (index, scope) in scopes.iter().enumerate() {
+                            if !scheme.flows.contains_scope(scope) {
+                                self.report(
+                                    format!("{}/{}", requirement_location, index),
+                                    DiagnosticKind::UndeclaredScope {
+                                        security_scheme: name.clone(),
+                                        scope: scope.clone(),
+                                    },
+                                );
+                            }
+                        }
+                    }
+                    Some(_) => {}
+                }
+            }
+        }
+
+        if let Some(request_body) = &operation.request_body {
+            self.validate_content(
+                &request_body.content,
+                &format!("{}/requestBody/content", location),
+            );
+        }
+
+        for (code, response) in &operation.responses {
+            let response_location = format!("{}/responses/{}", location, code);
+
+            for (name, header) in &response.headers {
+                if let Some(schema) = &header.schema {
+                    self.validate_reference(
+                        schema,
+                        &format!(
+                            "{}/headers/{}/schema",
+                            response_location,
+                            escape_pointer(name)
+                        ),
+                    );
+                }
+            }
+
+            self.validate_content(&response.content, &format!("{}/content", response_location));
+        }
+
+        self.validate_parameters(
+            &operation.parameters,
+            &format!("{}/parameters", location),
+            template_parameters,
+        );
+
+        for (name, callback) in &operation.callbacks {
+            // callback paths are runtime expressions, so templates are not checked
+            self.validate_paths(
+                &callback.paths,
+                &format!("{}/callbacks/{}", location, escape_pointer(name)),
+                false,
+            );
+        }
+    }
+
+    fn validate_content(&mut self, content: &IndexMap<String, MediaType>, location: &str) {
+        for (media_type_name, media_type) in content {
+            if let Some(schema) = &media_type.schema {
+                self.validate_reference(
+                    schema,
+                    &format!("{}/{}/schema", location, escape_pointer(media_type_name)),
+                );
+            }
+        }
+    }
+
+    fn validate_reference(&mut self, reference: &ModelReference, location: &str) {
+        match reference {
+            ModelReference::Link(link) => {
+                if !self.document.components.schemas.contains_key(link) {
+                    self.report(
+                        location.to_owned(),
+                        DiagnosticKind::DanglingReference(link.clone()),
+                    );
+                }
+            }
+            ModelReference::Inline(model) => self.validate_model(model, location),
+            ModelReference::Any => {}
+        }
+    }
+
+    fn validate_model(&mut self, model: &Model, location: &str) {
+        let (items, key) = match &model.data {
+            ModelData::Null(_) | ModelData::Any(_) => return,
+            ModelData::Single(single) => {
+                self.validate_type(&single.type_description, location);
+                return;
+            }
+            ModelData::OneOf(one_of) => (&one_of.one_of, "oneOf"),
+            ModelData::AllOf(all_of) => (&all_of.all_of, "allOf"),
+            ModelData::AnyOf(any_of) => (&any_of.any_of, "anyOf"),
+        };
 
-    /// A list of tags used by the specification with additional metadata
-    #[serde(
-        skip_serializing_if = "BTreeMap::is_empty",
-        serialize_with = "serialize_tags"
-    )]
-    pub tags: BTreeMap<String, Tag>,
+        for (index, item) in items.iter().enumerate() {
+            self.validate_reference(item, &format!("{}/{}/{}", location, key, index));
+        }
 
-    /// An array of Server Objects, which provide connectivity information to a target server
-    #[serde(skip_serializing_if = "Vec::is_empty")]
-    pub servers: Vec<Server>,
+        if let ModelData::OneOf(ModelOneOf {
+            discriminator: Some(discriminator),
+            ..
+        }) = &model.data
+        {
+            for (value, link) in &discriminator.mapping {
+                if !self.document.components.schemas.contains_key(link) {
+                    self.report(
+                        format!(
+                            "{}/discriminator/mapping/{}",
+                            location,
+                            escape_pointer(value)
+                        ),
+                        DiagnosticKind::DanglingReference(link.clone()),
+                    );
+                }
+            }
+        }
+    }
 
-    /// The available paths and operations for the API
-    #[serde(
-        serialize_with = "serialize_ordered_entries",
-        skip_serializing_if = "Vec::is_empty"
-    )]
-    pub paths: Vec<(Path, PathValue)>,
+    fn validate_type(&mut self, type_description: &ModelTypeDescription, location: &str) {
+        let (type_name, format) = match type_description {
+            ModelTypeDescription::String(string) => {
+                if let Some(variants) = &string.variants {
+                    if variants.is_empty() {
+                        self.report(format!("{}/enum", location), DiagnosticKind::EmptyEnum);
+                    }
+                    for (index, variant) in variants.iter().enumerate() {
+                        if variants[..index].contains(variant) {
+                            self.report(
+                                format!("{}/enum/{}", location, index),
+                                DiagnosticKind::DuplicateEnumVariant(variant.clone()),
+                            );
+                        }
+                    }
+                }
+                ("string", &string.data.format)
+            }
+            ModelTypeDescription::Integer(integer) => ("integer", &integer.format),
+            ModelTypeDescription::Number(number) => ("number", &number.format),
+            ModelTypeDescription::Boolean => return,
+            ModelTypeDescription::Array(array) => {
+                return self.validate_reference(&array.items, &format!("{}/items", location));
+            }
+            ModelTypeDescription::Object(object) => {
+                for (name, property) in &object.properties {
+                    self.validate_reference(
+                        property,
+                        &format!("{}/properties/{}", location, escape_pointer(name)),
+                    );
+                }
+                if let Some(additional_properties) = &object.additional_properties {
+                    self.validate_reference(
+                        additional_properties,
+                        &format!("{}/additionalProperties", location),
+                    );
+                }
+                return;
+            }
+        };
 
-    /// An element to hold various schemas for the specification
-    pub components: Components,
+        let format = match format {
+            Some(format) => format,
+            None => return,
+        };
+
+        let is_integer_format = matches!(
+            format.as_str(),
+            "int8"
+                | "int16"
+                | "int32"
+                | "int64"
+                | "int128"
+                | "uint8"
+                | "uint16"
+                | "uint32"
+                | "uint64"
+                | "uint128"
+        );
+        let is_number_format = matches!(format.as_str(), "float" | "double" | "decimal");
+
+        let valid = match type_name {
+            "integer" => is_integer_format,
+            "number" => is_number_format || is_integer_format,
+            _ => !is_integer_format && !is_number_format,
+        };
+
+        if !valid {
+            self.report(
+                format!("{}/format", location),
+                DiagnosticKind::InvalidFormat {
+                    type_name,
+                    format: format.clone(),
+                },
+            );
+        }
+    }
+}
+
+/// Escape JSON pointer reference token
+pub(crate) fn escape_pointer(token: &str) -> String {
+    token.replace('~', "~0").replace('/', "~1")
 }
 
 /// OpenAPI version.
 /// 3.0.3 by default
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OpenApiVersion(String);
 
 impl Default for OpenApiVersion {
@@ -69,6 +1316,41 @@ where
     ser.end()
 }
 
+/// Deserialize map as slice of tuples, preserving entries order
+fn deserialize_ordered_entries<'de, D, T1, T2>(deserializer: D) -> Result<Vec<(T1, T2)>, D::Error>
+where
+    T1: Deserialize<'de>,
+    T2: Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    struct OrderedEntriesVisitor<T1, T2>(std::marker::PhantomData<(T1, T2)>);
+
+    impl<'de, T1, T2> Visitor<'de> for OrderedEntriesVisitor<T1, T2>
+    where
+        T1: Deserialize<'de>,
+        T2: Deserialize<'de>,
+    {
+        type Value = Vec<(T1, T2)>;
+
+        fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+            formatter.write_str("a map")
+        }
+
+        fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+        where
+            A: MapAccess<'de>,
+        {
+            let mut entries = Vec::with_capacity(map.size_hint().unwrap_or_default());
+            while let Some(entry) = map.next_entry()? {
+                entries.push(entry);
+            }
+            Ok(entries)
+        }
+    }
+
+    deserializer.deserialize_map(OrderedEntriesVisitor(std::marker::PhantomData))
+}
+
 /// Serialize map of tags as sequence
 fn serialize_tags<S>(tags: &BTreeMap<String, Tag>, serializer: S) -> Result<S::Ok, S::Error>
 where
@@ -93,10 +1375,36 @@ where
     ser.end()
 }
 
+/// Deserialize sequence of tags as map
+fn deserialize_tags<'de, D>(deserializer: D) -> Result<BTreeMap<String, Tag>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    pub struct OpgTagHelper {
+        name: String,
+        #[serde(default)]
+        description: Option<String>,
+    }
+
+    Ok(Vec::<OpgTagHelper>::deserialize(deserializer)?
+        .into_iter()
+        .map(|tag| {
+            (
+                tag.name,
+                Tag {
+                    description: tag.description,
+                },
+            )
+        })
+        .collect())
+}
+
 /// Info Object
 ///
 /// [specification](https://swagger.io/specification/#info-object)
-#[derive(Debug, Clone, Default, Serialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
 pub struct Info {
     /// The title of the API
     pub title: String,
@@ -112,7 +1420,8 @@ pub struct Info {
 /// Tag Object
 ///
 /// [specification](https://swagger.io/specification/#tag-object)
-#[derive(Debug, Clone, Default, Serialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
 pub struct Tag {
     /// A short description for the tag
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -122,29 +1431,72 @@ pub struct Tag {
 /// Server Object
 ///
 /// [specification](https://swagger.io/specification/#server-object)
-///
-/// TODO: add variables section
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Server {
-    /// A URL to the target host
+    /// A URL to the target host. May contain variables in `{braces}`
     pub url: String,
 
     /// An optional string describing the host designated by the URL
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
+
+    /// A map between a variable name and its value, used for substitution in the URL template
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub variables: BTreeMap<String, ServerVariable>,
+}
+
+/// Server Variable Object
+///
+/// [specification](https://swagger.io/specification/#server-variable-object)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ServerVariable {
+    /// A limited set of values for the substitution
+    #[serde(rename = "enum", skip_serializing_if = "Vec::is_empty")]
+    pub variants: Vec<String>,
+
+    /// The default value to use for substitution
+    pub default: String,
+
+    /// An optional description for the server variable
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
 }
 
 /// Paths Object key
 ///
 /// [specification](https://swagger.io/specification/#paths-object)
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Eq, PartialEq, Serialize)]
 pub struct Path(#[serde(serialize_with = "serialize_path_elements")] pub Vec<PathElement>);
 
+impl Path {
+    /// Path elements with parameter names erased
+    fn template(&self) -> Vec<Option<&str>> {
+        self.0
+            .iter()
+            .map(|element| match element {
+                PathElement::Path(path) => Some(path.as_str()),
+                PathElement::Parameter(_) => None,
+            })
+            .collect()
+    }
+}
+
+impl std::fmt::Display for Path {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&format_path_elements(&self.0))
+    }
+}
+
 /// Serialize sequence of path elements as single string
 fn serialize_path_elements<S>(elements: &[PathElement], serializer: S) -> Result<S::Ok, S::Error>
 where
     S: Serializer,
 {
+    serializer.serialize_str(&format_path_elements(elements))
+}
+
+fn format_path_elements(elements: &[PathElement]) -> String {
     let mut result = String::new();
 
     for element in elements.iter().map(|element| match element {
@@ -154,7 +1506,31 @@ where
         write!(&mut result, "/{}", element).unwrap();
     }
 
-    serializer.serialize_str(&result)
+    result
+}
+
+impl<'de> Deserialize<'de> for Path {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let path = String::deserialize(deserializer)?;
+
+        Ok(Path(
+            path.split('/')
+                .filter(|element| !element.is_empty())
+                .map(|element| {
+                    match element
+                        .strip_prefix('{')
+                        .and_then(|element| element.strip_suffix('}'))
+                    {
+                        Some(param) => PathElement::Parameter(param.to_owned()),
+                        None => PathElement::Path(element.to_owned()),
+                    }
+                })
+                .collect(),
+        ))
+    }
 }
 
 /// Paths Object key part.
@@ -165,7 +1541,7 @@ where
 /// `PathElement::Path("pets"), PathElement::Parameter("petId")`
 ///
 /// [specification](https://swagger.io/specification/#paths-object)
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Eq, PartialEq)]
 pub enum PathElement {
     Path(String),
     Parameter(String),
@@ -196,6 +1572,113 @@ pub struct PathValue {
     pub parameters: BTreeMap<String, OperationParameter>,
 }
 
+impl<'de> Deserialize<'de> for PathValue {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        struct ParametersHelper(
+            #[serde(deserialize_with = "deserialize_parameters")]
+            BTreeMap<String, OperationParameter>,
+        );
+
+        struct PathValueVisitor;
+
+        impl<'de> Visitor<'de> for PathValueVisitor {
+            type Value = PathValue;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                formatter.write_str("a path item object")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut result = PathValue::default();
+
+                while let Some(key) = map.next_key::<String>()? {
+                    match key.as_str() {
+                        "summary" => result.summary = map.next_value()?,
+                        "description" => result.description = map.next_value()?,
+                        "parameters" => result.parameters = map.next_value::<ParametersHelper>()?.0,
+                        // silently dropping them would change the meaning of the path
+                        "$ref" | "servers" => {
+                            return Err(de::Error::custom(format_args!(
+                                "`{}` in path items is not supported",
+                                key
+                            )))
+                        }
+                        key => match HttpMethod::from_str(key) {
+                            Some(method) => {
+                                result.operations.insert(method, map.next_value()?);
+                            }
+                            // extensions are not supported yet
+                            None => {
+                                map.next_value::<IgnoredAny>()?;
+                            }
+                        },
+                    }
+                }
+
+                Ok(result)
+            }
+        }
+
+        deserializer.deserialize_map(PathValueVisitor)
+    }
+}
+
+impl PathValue {
+    /// Find method which is defined in both paths
+    fn find_common_method<'a>(&'a self, other: &PathValue) -> Option<&'a HttpMethod> {
+        self.operations
+            .keys()
+            .find(|method| other.operations.contains_key(method))
+    }
+
+    /// Add missing operations, parameters and descriptions from other path
+    fn merge(&mut self, other: PathValue) {
+        if self.summary.is_none() {
+            self.summary = other.summary;
+        }
+        if self.description.is_none() {
+            self.description = other.description;
+        }
+        for (method, operation) in other.operations {
+            self.operations.entry(method).or_insert(operation);
+        }
+        for (name, parameter) in other.parameters {
+            self.parameters.entry(name).or_insert(parameter);
+        }
+    }
+
+    /// Replace schema links and security scheme names using specified renames
+    fn rename_components(&mut self, renames: &ComponentRenames) {
+        self.parameters
+            .values_mut()
+            .for_each(|parameter| parameter.rename_links(&renames.schemas));
+        self.operations
+            .values_mut()
+            .for_each(|operation| operation.rename_components(renames));
+    }
+
+    /// Visit all models of this path, including inline ones
+    fn for_each_model_mut<F>(&mut self, f: &mut F)
+    where
+        F: FnMut(&mut Model),
+    {
+        self.parameters
+            .values_mut()
+            .filter_map(|parameter| parameter.schema.as_mut())
+            .for_each(|schema| schema.for_each_model_mut(f));
+        self.operations
+            .values_mut()
+            .for_each(|operation| operation.for_each_model_mut(f));
+    }
+}
+
 /// Path Item Object operation type
 ///
 /// [specification](https://swagger.io/specification/#path-item-object)
@@ -224,6 +1707,20 @@ impl HttpMethod {
             HttpMethod::TRACE => "trace",
         }
     }
+
+    fn from_str(method: &str) -> Option<Self> {
+        Some(match method {
+            "get" => HttpMethod::GET,
+            "put" => HttpMethod::PUT,
+            "post" => HttpMethod::POST,
+            "delete" => HttpMethod::DELETE,
+            "options" => HttpMethod::OPTIONS,
+            "head" => HttpMethod::HEAD,
+            "patch" => HttpMethod::PATCH,
+            "trace" => HttpMethod::TRACE,
+            _ => return None,
+        })
+    }
 }
 
 impl Serialize for HttpMethod {
@@ -235,10 +1732,25 @@ impl Serialize for HttpMethod {
     }
 }
 
+impl<'de> Deserialize<'de> for HttpMethod {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const VARIANTS: &[&str] = &[
+            "get", "put", "post", "delete", "options", "head", "patch", "trace",
+        ];
+
+        let method = String::deserialize(deserializer)?;
+        HttpMethod::from_str(&method).ok_or_else(|| de::Error::unknown_variant(&method, VARIANTS))
+    }
+}
+
 /// Operation Object
 ///
 /// [specification](https://swagger.io/specification/#operation-object)
-#[derive(Debug, Clone, Default, Serialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
 #[serde(rename_all = "camelCase")]
 pub struct Operation {
     /// A list of tags for API documentation control
@@ -272,12 +1784,13 @@ pub struct Operation {
     pub request_body: Option<RequestBody>,
 
     /// The list of possible responses as they are returned from executing this operation
-    pub responses: BTreeMap<u16, Response>,
+    pub responses: BTreeMap<ResponseCode, Response>,
 
     /// A list of parameters that are applicable for this operation
     #[serde(
         skip_serializing_if = "BTreeMap::is_empty",
-        serialize_with = "serialize_parameters"
+        serialize_with = "serialize_parameters",
+        deserialize_with = "deserialize_parameters"
     )]
     pub parameters: BTreeMap<String, OperationParameter>,
 
@@ -320,95 +1833,416 @@ impl Operation {
         self.request_body = Some(body);
         self
     }
+
+    /// Replace schema links and security scheme names using specified renames
+    fn rename_components(&mut self, renames: &ComponentRenames) {
+        self.security = std::mem::take(&mut self.security)
+            .into_iter()
+            .map(|requirement| {
+                requirement
+                    .into_iter()
+                    .map(|(name, scopes)| match renames.security_schemes.get(&name) {
+                        Some(name) => (name.clone(), scopes),
+                        None => (name, scopes),
+                    })
+                    .collect()
+            })
+            .collect();
+
+        if let Some(request_body) = &mut self.request_body {
+            request_body
+                .content
+                .values_mut()
+                .for_each(|media_type| media_type.rename_links(&renames.schemas));
+        }
+
+        self.responses.values_mut().for_each(|response| {
+            response
+                .headers
+                .values_mut()
+                .filter_map(|header| header.schema.as_mut())
+                .chain(
+                    response
+                        .content
+                        .values_mut()
+                        .filter_map(|media_type| media_type.schema.as_mut()),
+                )
+                .for_each(|schema| schema.rename_links(&renames.schemas))
+        });
+
+        self.parameters
+            .values_mut()
+            .for_each(|parameter| parameter.rename_links(&renames.schemas));
+
+        self.callbacks
+            .values_mut()
+            .flat_map(|callback| callback.paths.iter_mut())
+            .for_each(|(_, path_value)| path_value.rename_components(renames));
+    }
+
+    /// Visit all models of this operation, including inline ones
+    fn for_each_model_mut<F>(&mut self, f: &mut F)
+    where
+        F: FnMut(&mut Model),
+    {
+        let request_schemas = self
+            .request_body
+            .iter_mut()
+            .flat_map(|request_body| request_body.content.values_mut())
+            .filter_map(|media_type| media_type.schema.as_mut());
+
+        let response_schemas = self.responses.values_mut().flat_map(|response| {
+            response
+                .headers
+                .values_mut()
+                .filter_map(|header| header.schema.as_mut())
+                .chain(
+                    response
+                        .content
+                        .values_mut()
+                        .filter_map(|media_type| media_type.schema.as_mut()),
+                )
+        });
+
+        let parameter_schemas = self
+            .parameters
+            .values_mut()
+            .filter_map(|parameter| parameter.schema.as_mut());
+
+        request_schemas
+            .chain(response_schemas)
+            .chain(parameter_schemas)
+            .for_each(|schema| schema.for_each_model_mut(f));
+
+        self.callbacks
+            .values_mut()
+            .flat_map(|callback| callback.paths.iter_mut())
+            .for_each(|(_, path_value)| path_value.for_each_model_mut(f));
+    }
 }
 
-/// Request Body Object
+/// Key of the Responses Object
 ///
-/// [specification](https://swagger.io/specification/#request-body-object)
-#[derive(Debug, Clone)]
-pub struct RequestBody {
-    /// A brief description of the request body
-    pub description: Option<String>,
+/// [specification](https://swagger.io/specification/#responses-object)
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum ResponseCode {
+    /// Exact HTTP status code, e.g. `200`
+    Status(u16),
+    /// Range of status codes by the first digit, e.g. `2` for `2XX`
+    Range(u8),
+    /// Response for all codes which are not covered individually
+    Default,
+}
 
-    /// Determines if the request body is required in the request. Defaults to true.
-    pub required: bool,
+impl ResponseCode {
+    fn sort_key(&self) -> (u16, bool, u16) {
+        match *self {
+            ResponseCode::Status(code) => (code / 100, false, code),
+            ResponseCode::Range(class) => (class as u16, true, 0),
+            ResponseCode::Default => (u16::MAX, true, 0),
+        }
+    }
+
+    fn from_str(code: &str) -> Option<Self> {
+        match code.as_bytes() {
+            b"default" => Some(ResponseCode::Default),
+            [class @ b'1'..=b'5', b'X', b'X'] => Some(ResponseCode::Range(class - b'0')),
+            _ => code.parse().ok().map(ResponseCode::Status),
+        }
+    }
+}
 
-    /// The content of the request body
-    pub schema: ModelReference,
+impl From<u16> for ResponseCode {
+    fn from(code: u16) -> Self {
+        ResponseCode::Status(code)
+    }
+}
+
+impl PartialEq<u16> for ResponseCode {
+    fn eq(&self, other: &u16) -> bool {
+        *self == ResponseCode::Status(*other)
+    }
+}
+
+impl PartialOrd for ResponseCode {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for ResponseCode {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.sort_key().cmp(&other.sort_key())
+    }
+}
+
+impl std::fmt::Display for ResponseCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ResponseCode::Status(code) => code.fmt(f),
+            ResponseCode::Range(class) => write!(f, "{}XX", class),
+            ResponseCode::Default => f.write_str("default"),
+        }
+    }
 }
 
-impl Serialize for RequestBody {
+impl Serialize for ResponseCode {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
-        #[derive(Serialize)]
-        struct RequestBodyHelper<'a> {
-            #[serde(skip_serializing_if = "is_false")]
-            required: bool,
-            description: &'a Option<String>,
-            content: ResponseContent<'a>,
-        }
-
-        RequestBodyHelper {
-            required: self.required,
-            description: &self.description,
-            content: ResponseContent {
-                media_type: ResponseMediaType {
-                    schema: &self.schema,
-                },
-            },
+        match self {
+            ResponseCode::Status(code) => serializer.serialize_u16(*code),
+            code => serializer.collect_str(code),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for ResponseCode {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct ResponseCodeVisitor;
+
+        impl<'de> Visitor<'de> for ResponseCodeVisitor {
+            type Value = ResponseCode;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                formatter.write_str("a response status code, a range like `2XX` or `default`")
+            }
+
+            fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                std::convert::TryFrom::try_from(value)
+                    .map(ResponseCode::Status)
+                    .map_err(|_| E::invalid_value(de::Unexpected::Unsigned(value), &self))
+            }
+
+            fn visit_i64<E>(self, value: i64) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                std::convert::TryFrom::try_from(value)
+                    .map(ResponseCode::Status)
+                    .map_err(|_| E::invalid_value(de::Unexpected::Signed(value), &self))
+            }
+
+            fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                ResponseCode::from_str(value)
+                    .ok_or_else(|| E::invalid_value(de::Unexpected::Str(value), &self))
+            }
         }
-        .serialize(serializer)
+
+        deserializer.deserialize_any(ResponseCodeVisitor)
     }
 }
 
+/// Request Body Object
+///
+/// [specification](https://swagger.io/specification/#request-body-object)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RequestBody {
+    /// Determines if the request body is required in the request. Defaults to true.
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub required: bool,
+
+    /// A brief description of the request body
+    pub description: Option<String>,
+
+    /// The content of the request body, keyed by media type in the order of declaration
+    #[serde(default)]
+    pub content: IndexMap<String, MediaType>,
+}
+
 /// Response Object
 ///
 /// [specification](https://swagger.io/specification/#response-object)
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Response {
     /// A short description of the response
     pub description: String,
 
-    /// Response schema
-    pub schema: Option<ModelReference>,
+    /// Response headers, keyed by header name
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub headers: BTreeMap<String, Header>,
+
+    /// Response contents, keyed by media type in the order of declaration
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
+    pub content: IndexMap<String, MediaType>,
+
+    /// Operations that can be followed from the response
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub links: BTreeMap<String, Link>,
+}
+
+/// Header Object
+///
+/// Follows the structure of the Parameter Object without `name` and `in`.
+///
+/// [specification](https://swagger.io/specification/#header-object)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Header {
+    /// A brief description of the header
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// Determines whether this header is mandatory
+    #[serde(skip_serializing_if = "is_false")]
+    pub required: bool,
+
+    /// Declares this header to be deprecated
+    #[serde(skip_serializing_if = "is_false")]
+    pub deprecated: bool,
+
+    /// The schema defining the type used for the header
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schema: Option<ModelReference>,
+}
+
+/// Link Object
+///
+/// [specification](https://swagger.io/specification/#link-object)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+#[serde(rename_all = "camelCase")]
+pub struct Link {
+    /// A relative or absolute URI reference to an OAS operation
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub operation_ref: Option<String>,
+
+    /// The name of an existing, resolvable OAS operation
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub operation_id: Option<String>,
+
+    /// Parameters to pass to the linked operation, as constants or runtime expressions
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub parameters: BTreeMap<String, serde_json::Value>,
+
+    /// A constant or runtime expression to use as a request body for the linked operation
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_body: Option<serde_json::Value>,
+
+    /// A description of the link
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+/// helper for serde
+#[allow(clippy::trivially_copy_pass_by_ref)]
+fn is_false(value: &bool) -> bool {
+    !*value
+}
+
+/// Media Type Object
+///
+/// [specification](https://swagger.io/specification/#media-type-object)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MediaType {
+    /// The schema defining the content of the request or response
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schema: Option<ModelReference>,
+
+    /// Example of the media type
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub example: Option<serde_json::Value>,
+
+    /// Named examples of the media type
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub examples: BTreeMap<String, Example>,
+
+    /// A map between a property name and its encoding information
+    ///
+    /// Applies only to `multipart` and `application/x-www-form-urlencoded` request bodies.
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub encoding: BTreeMap<String, Encoding>,
 }
 
-impl Serialize for Response {
-    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        S: Serializer,
-    {
-        #[derive(Serialize)]
-        struct ResponseHelper<'a> {
-            description: &'a str,
-            #[serde(skip_serializing_if = "Option::is_none")]
-            content: Option<ResponseContent<'a>>,
+impl MediaType {
+    /// Media type with only schema specified
+    pub fn with_schema(schema: ModelReference) -> Self {
+        Self {
+            schema: Some(schema),
+            ..Default::default()
         }
+    }
 
-        ResponseHelper {
-            description: &self.description,
-            content: self.schema.as_ref().map(|schema| ResponseContent {
-                media_type: ResponseMediaType { schema },
-            }),
+    /// Replace links using specified renames
+    fn rename_links(&mut self, renames: &BTreeMap<String, String>) {
+        if let Some(schema) = &mut self.schema {
+            schema.rename_links(renames);
         }
-        .serialize(serializer)
     }
 }
 
-/// helper for serde
-#[allow(clippy::trivially_copy_pass_by_ref)]
-fn is_false(value: &bool) -> bool {
-    !*value
+/// Example Object
+///
+/// [specification](https://swagger.io/specification/#example-object)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+#[serde(rename_all = "camelCase")]
+pub struct Example {
+    /// Short description for the example
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+
+    /// Long description for the example
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// Embedded literal example
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<serde_json::Value>,
+
+    /// A URL that points to the literal example
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub external_value: Option<String>,
+}
+
+/// Encoding Object
+///
+/// [specification](https://swagger.io/specification/#encoding-object)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+#[serde(rename_all = "camelCase")]
+pub struct Encoding {
+    /// The Content-Type for encoding a specific property
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content_type: Option<String>,
+
+    /// Describes how a specific property value will be serialized
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub style: Option<ParameterStyle>,
+
+    /// Whether values of type array or object generate separate parameters
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub explode: Option<bool>,
+
+    /// Whether reserved characters are allowed without percent-encoding
+    #[serde(skip_serializing_if = "is_false")]
+    pub allow_reserved: bool,
 }
 
-/// Response media type.
+/// Describes how the parameter value will be serialized
 ///
-/// Currently behaves as a stub with value 'application/json'
-#[derive(Serialize)]
-struct ResponseMediaType<'a> {
-    schema: &'a ModelReference,
+/// [specification](https://swagger.io/specification/#style-values)
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ParameterStyle {
+    Matrix,
+    Label,
+    Form,
+    Simple,
+    SpaceDelimited,
+    PipeDelimited,
+    DeepObject,
 }
 
 /// Callback Object
@@ -425,11 +2259,13 @@ pub struct CallbackObject {
     pub paths: Vec<(Path, PathValue)>,
 }
 
-/// Content Object
-#[derive(Serialize)]
-struct ResponseContent<'a> {
-    #[serde(rename = "application/json")]
-    media_type: ResponseMediaType<'a>,
+impl<'de> Deserialize<'de> for CallbackObject {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize_ordered_entries(deserializer).map(|paths| CallbackObject { paths })
+    }
 }
 
 /// Serialize map of parameters as sequence
@@ -472,6 +2308,45 @@ where
     ser.end()
 }
 
+/// Deserialize sequence of parameters as map
+fn deserialize_parameters<'de, D>(
+    deserializer: D,
+) -> Result<BTreeMap<String, OperationParameter>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    struct OperationParameterHelper {
+        name: String,
+        #[serde(default)]
+        description: Option<String>,
+        #[serde(rename = "in")]
+        parameter_in: ParameterIn,
+        #[serde(default)]
+        required: bool,
+        #[serde(default)]
+        deprecated: bool,
+        #[serde(default)]
+        schema: Option<ModelReference>,
+    }
+
+    Ok(Vec::<OperationParameterHelper>::deserialize(deserializer)?
+        .into_iter()
+        .map(|parameter| {
+            (
+                parameter.name,
+                OperationParameter {
+                    description: parameter.description,
+                    parameter_in: parameter.parameter_in,
+                    required: parameter.required,
+                    deprecated: parameter.deprecated,
+                    schema: parameter.schema,
+                },
+            )
+        })
+        .collect())
+}
+
 /// Parameter Object
 ///
 /// [specification](https://swagger.io/specification/#parameter-object)
@@ -496,8 +2371,17 @@ pub struct OperationParameter {
     pub schema: Option<ModelReference>,
 }
 
+impl OperationParameter {
+    /// Replace links using specified renames
+    fn rename_links(&mut self, renames: &BTreeMap<String, String>) {
+        if let Some(schema) = &mut self.schema {
+            schema.rename_links(renames);
+        }
+    }
+}
+
 /// The location of the parameter
-#[derive(Debug, Clone, Copy, Serialize)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum ParameterIn {
     Query,
@@ -509,7 +2393,8 @@ pub enum ParameterIn {
 /// Components Object
 ///
 /// [specification](https://swagger.io/specification/#components-object)
-#[derive(Debug, Clone, Default, Serialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
 #[serde(rename_all = "camelCase")]
 pub struct Components {
     /// An object to hold reusable Schema Objects
@@ -519,6 +2404,65 @@ pub struct Components {
     /// An object to hold reusable Security Scheme Objects
     #[serde(skip_serializing_if = "BTreeMap::is_empty")]
     pub security_schemes: BTreeMap<String, SecurityScheme>,
+
+    /// Direction of currently generated schemas
+    #[serde(skip)]
+    direction: Option<SchemaDirection>,
+
+    /// Whether schemas have separate request and response variants, by schema name
+    #[serde(skip)]
+    directional: BTreeMap<String, bool>,
+
+    /// Whether currently generated schema depends on direction
+    #[serde(skip)]
+    directional_mentioned: bool,
+
+    /// Schemas whose direction difference is not known yet, because they are being generated
+    #[serde(skip)]
+    directional_pending: BTreeSet<String>,
+
+    /// Pending schemas which were linked recursively as shared ones
+    #[serde(skip)]
+    directional_recursive: BTreeSet<String>,
+
+    /// Entries inserted while some directional schema is pending, in insertion order
+    #[serde(skip)]
+    directional_generated: Vec<GeneratedEntry>,
+
+    /// Tagged enum name and variant name, by variant schema name
+    #[serde(skip)]
+    variant_schemas: BTreeMap<String, (String, String)>,
+
+    /// Schema names which are claimed by several types or enum variants
+    #[serde(skip)]
+    name_collisions: BTreeSet<String>,
+}
+
+/// Entry of `Components` which may be discarded when a recursive directional schema
+/// is generated again
+#[derive(Debug, Clone)]
+enum GeneratedEntry {
+    Schema(String),
+    Direction(String),
+}
+
+/// Side of the API where the schema is used
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum SchemaDirection {
+    /// Deserialized value, e.g. request body
+    Request,
+    /// Serialized value, e.g. response body
+    Response,
+}
+
+impl SchemaDirection {
+    /// Suffix of direction specific schema names
+    pub fn suffix(self) -> &'static str {
+        match self {
+            SchemaDirection::Request => "Request",
+            SchemaDirection::Response => "Response",
+        }
+    }
 }
 
 impl Components {
@@ -537,14 +2481,152 @@ impl Components {
     where
         M: OpgModel + ?Sized,
     {
-        let reference = M::select_reference(self, inline, params);
+        let link = match M::select_reference(self, inline, params) {
+            ModelReference::Link(link) => link,
+            reference => return reference,
+        };
+
+        let reference = match self.direction {
+            Some(direction) => self.mention_directional_schema::<M>(link, direction),
+            None => {
+                if !self.schemas.contains_key(&link) {
+                    // Reserve the name before generating the schema, so that recursive types
+                    // produce a `$ref` cycle instead of infinite recursion
+                    self.schemas.insert(link.clone(), Model::placeholder());
+
+                    let structure = M::get_schema(self);
+                    self.schemas.insert(link.clone(), structure);
+                }
+                ModelReference::Link(link)
+            }
+        };
+
         if let ModelReference::Link(link) = &reference {
-            if !self.schemas.contains_key(link) {
-                let structure = M::get_schema(self);
-                self.schemas.insert(link.to_owned(), structure);
+            if self.variant_schemas.contains_key(link) {
+                self.name_collisions.insert(link.clone());
+            }
+        }
+
+        reference.apply_params(params)
+    }
+
+    /// Insert schema for the specified direction
+    ///
+    /// Schemas which differ between requests and responses (or reference such schemas)
+    /// are stored as `<Name>Request` and `<Name>Response`
+    fn mention_directional_schema<M>(
+        &mut self,
+        name: String,
+        direction: SchemaDirection,
+    ) -> ModelReference
+    where
+        M: OpgModel + ?Sized,
+    {
+        let directional_name = format!("{}{}", name, direction.suffix());
+
+        match self.directional.get(&name) {
+            Some(false) => {
+                if self.directional_pending.contains(&name) {
+                    self.directional_recursive.insert(name.clone());
+                }
+                return ModelReference::Link(name);
+            }
+            Some(true) => {
+                if !self.schemas.contains_key(&directional_name) {
+                    self.insert_generated(directional_name.clone(), Model::placeholder());
+
+                    let structure = M::get_schema(self);
+                    self.schemas.insert(directional_name.clone(), structure);
+                }
+                self.directional_mentioned = true;
+                return ModelReference::Link(directional_name);
+            }
+            None => {}
+        }
+
+        // Recursive mentions link the shared schema until the difference is known
+        let generated_start = self.directional_generated.len();
+        let name_collisions = self.name_collisions.clone();
+        self.directional.insert(name.clone(), false);
+        self.directional_pending.insert(name.clone());
+
+        let outer_mentioned = std::mem::replace(&mut self.directional_mentioned, false);
+        let mut structure = M::get_schema(self);
+        let directional = self.directional_mentioned;
+        self.directional_mentioned = outer_mentioned;
+
+        self.directional_pending.remove(&name);
+        let linked_recursively = self.directional_recursive.remove(&name);
+
+        if directional && linked_recursively {
+            // Schemas on the recursion path link the shared schema, so everything generated
+            // since is discarded and generated again with the known difference
+            for generated in self.directional_generated.drain(generated_start..) {
+                match generated {
+                    GeneratedEntry::Schema(name) => {
+                        self.schemas.remove(&name);
+                        self.variant_schemas.remove(&name);
+                    }
+                    GeneratedEntry::Direction(name) => {
+                        self.directional.remove(&name);
+                    }
+                }
+            }
+            self.name_collisions = name_collisions;
+            self.directional.insert(name.clone(), true);
+            self.log_generated(GeneratedEntry::Direction(name.clone()));
+
+            return self.mention_directional_schema::<M>(name, direction);
+        }
+
+        self.directional_mentioned |= directional;
+        self.log_generated(GeneratedEntry::Direction(name.clone()));
+
+        if directional {
+            self.directional.insert(name.clone(), true);
+
+            let renames = std::iter::once((name, directional_name.clone())).collect();
+            structure.rename_links(&renames);
+
+            self.insert_generated(directional_name.clone(), structure);
+            ModelReference::Link(directional_name)
+        } else {
+            if !self.schemas.contains_key(&name) {
+                self.insert_generated(name.clone(), structure);
             }
+            ModelReference::Link(name)
+        }
+    }
+
+    /// Insert schema, remembering it while some directional schema is pending
+    fn insert_generated(&mut self, name: String, model: Model) {
+        self.log_generated(GeneratedEntry::Schema(name.clone()));
+        self.schemas.insert(name, model);
+    }
+
+    fn log_generated(&mut self, entry: GeneratedEntry) {
+        if self.directional_pending.is_empty() {
+            self.directional_generated.clear();
+        } else {
+            self.directional_generated.push(entry);
         }
-        reference
+    }
+
+    /// Direction of currently generated schemas
+    #[inline]
+    pub fn direction(&self) -> Option<SchemaDirection> {
+        self.direction
+    }
+
+    /// Set direction of generated schemas, returning the previous one
+    pub fn set_direction(&mut self, direction: Option<SchemaDirection>) -> Option<SchemaDirection> {
+        std::mem::replace(&mut self.direction, direction)
+    }
+
+    /// Mark currently generated schema as having different request and response representations
+    #[inline]
+    pub fn mark_directional(&mut self) {
+        self.directional_mentioned = true;
     }
 
     /// Insert security scheme and return it's name
@@ -571,15 +2653,69 @@ impl Components {
             .map_err(|first_occurrence| first_occurrence.to_owned())
     }
 
+    /// Insert variant schema of the tagged enum as `<EnumName><VariantName>`
+    /// and register it in the discriminator mapping
+    ///
+    /// Variants which differ between requests and responses get the direction suffix.
+    /// Variants of enums without a name stay inlined. If the name is already used by
+    /// another type or variant, the first schema is kept and the collision is reported
+    /// by [`Opg::validate`]
+    pub fn mention_variant_schema<F>(
+        &mut self,
+        type_name: Option<&str>,
+        variant_ident: &str,
+        discriminator_value: &str,
+        discriminator: &mut ModelDiscriminator,
+        model: F,
+    ) -> ModelReference
+    where
+        F: FnOnce(&mut Self) -> Model,
+    {
+        let outer_mentioned = std::mem::replace(&mut self.directional_mentioned, false);
+        let model = model(self);
+        let directional = self.directional_mentioned;
+        self.directional_mentioned |= outer_mentioned;
+
+        match type_name {
+            Some(type_name) => {
+                let mut link = format!("{}{}", type_name, variant_ident);
+                if let (true, Some(direction)) = (directional, self.direction) {
+                    link.push_str(direction.suffix());
+                }
+
+                let owner = (type_name.to_owned(), variant_ident.to_owned());
+
+                match self.variant_schemas.get(&link) {
+                    Some(existing) if existing != &owner => {
+                        self.name_collisions.insert(link.clone());
+                    }
+                    Some(_) => {}
+                    None if self.schemas.contains_key(&link) => {
+                        self.name_collisions.insert(link.clone());
+                    }
+                    None => {
+                        self.variant_schemas.insert(link.clone(), owner);
+                    }
+                }
+
+                self.add_model(&link, model);
+                discriminator
+                    .mapping
+                    .insert(discriminator_value.to_owned(), link.clone());
+                ModelReference::Link(link)
+            }
+            None => ModelReference::Inline(model),
+        }
+    }
+
     /// Manually insert model with specified name
     pub fn add_model<N>(&mut self, name: N, model: Model)
     where
         N: ToString,
     {
-        if let std::collections::btree_map::Entry::Vacant(entry) =
-            self.schemas.entry(name.to_string())
-        {
-            entry.insert(model);
+        let name = name.to_string();
+        if !self.schemas.contains_key(&name) {
+            self.insert_generated(name, model);
         }
     }
 }
@@ -611,8 +2747,89 @@ pub trait OpgModel {
     }
 }
 
+/// Get type name without module paths, suitable for schema names
+///
+/// Used as a generic argument name part when argument type has no own schema name.
+/// For example `alloc::vec::Vec<my_crate::User>` becomes `Vec_User`
+pub fn short_type_name<T>() -> Cow<'static, str>
+where
+    T: ?Sized,
+{
+    let full_name = std::any::type_name::<T>();
+
+    let mut result = String::with_capacity(full_name.len());
+    for path in full_name
+        .split(|c: char| !(c.is_alphanumeric() || c == '_' || c == ':'))
+        .filter(|path| !path.is_empty())
+    {
+        let name = path.rsplit("::").next().unwrap_or(path);
+        if !result.is_empty() {
+            result.push('_');
+        }
+        result.push_str(name);
+    }
+
+    result.into()
+}
+
+/// Serialize example value into JSON
+///
+/// Returns `None` if the value can't be represented as JSON (e.g. a map with non-string keys),
+/// so that the example is omitted instead of failing the whole description. Such examples
+/// are reported by a debug assertion
+pub fn example_value<T>(value: &T) -> Option<serde_json::Value>
+where
+    T: Serialize + ?Sized,
+{
+    match serde_json::to_value(value) {
+        Ok(value) => Some(value),
+        Err(e) => {
+            debug_assert!(false, "example can't be serialized into JSON: {}", e);
+            None
+        }
+    }
+}
+
+/// Default value of a field, evaluated from the value of type `C` with the projection `F`
+///
+/// The value is only emitted when `C: Default` and the projected value can be serialized.
+/// Otherwise the [`OpaqueDefault`] fallback is selected by the method resolution
+#[doc(hidden)]
+pub struct DefaultValue<C, F>(PhantomData<C>, F);
+
+impl<C, F> DefaultValue<C, F> {
+    pub fn new(projection: F) -> Self {
+        Self(PhantomData, projection)
+    }
+}
+
+#[doc(hidden)]
+pub trait SerializableDefault {
+    fn default_value(&self) -> Option<serde_json::Value>;
+}
+
+impl<C, F, T> SerializableDefault for DefaultValue<C, F>
+where
+    C: Default,
+    F: Fn(C) -> T,
+    T: Serialize,
+{
+    fn default_value(&self) -> Option<serde_json::Value> {
+        serde_json::to_value((self.1)(C::default())).ok()
+    }
+}
+
+#[doc(hidden)]
+pub trait OpaqueDefault {
+    fn default_value(&self) -> Option<serde_json::Value> {
+        None
+    }
+}
+
+impl<C, F> OpaqueDefault for &DefaultValue<C, F> {}
+
 /// Context parameters
-#[derive(Default)]
+#[derive(Clone, Default)]
 pub struct ContextParams {
     /// Brief description of this object inplace
     pub description: Option<String>,
@@ -628,13 +2845,59 @@ pub struct ContextParams {
     pub format: Option<String>,
 
     /// Example for this object inplace
-    pub example: Option<String>,
+    pub example: Option<serde_json::Value>,
+
+    /// Minimal numeric value inplace
+    pub minimum: Option<serde_json::Number>,
+
+    /// Maximal numeric value inplace
+    pub maximum: Option<serde_json::Number>,
+
+    /// Whether the minimal numeric value is excluded inplace
+    pub exclusive_minimum: Option<bool>,
+
+    /// Whether the maximal numeric value is excluded inplace
+    pub exclusive_maximum: Option<bool>,
+
+    /// Numeric value divisor inplace
+    pub multiple_of: Option<serde_json::Number>,
+
+    /// Minimal string length inplace
+    pub min_length: Option<usize>,
+
+    /// Maximal string length inplace
+    pub max_length: Option<usize>,
+
+    /// String regular expression inplace
+    pub pattern: Option<String>,
+
+    /// Minimal number of array items inplace
+    pub min_items: Option<usize>,
+
+    /// Maximal number of array items inplace
+    pub max_items: Option<usize>,
+
+    /// Whether array items must be unique inplace
+    pub unique_items: Option<bool>,
+
+    /// Minimal number of object properties inplace
+    pub min_properties: Option<usize>,
+
+    /// Maximal number of object properties inplace
+    pub max_properties: Option<usize>,
+}
+
+impl ContextParams {
+    /// Whether params can't be applied to a `$ref` without sibling keywords
+    fn has_reference_siblings(&self) -> bool {
+        self.description.is_some() || self.example.is_some() || self.nullable == Some(true)
+    }
 }
 
 /// Schema Object
 ///
 /// [specification](https://swagger.io/specification/#schema-object)
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Model {
     /// Brief description of this object
@@ -644,15 +2907,49 @@ pub struct Model {
     /// Type specific data
     #[serde(flatten)]
     pub data: ModelData,
+
+    /// Example value
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub example: Option<serde_json::Value>,
+
+    /// Value used when this one is omitted in requests
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default: Option<serde_json::Value>,
+
+    /// Whether this value is only sent in responses
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub read_only: bool,
+
+    /// Whether this value is only accepted in requests
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub write_only: bool,
 }
 
 impl Model {
+    /// Empty object, used while the actual schema is being generated
+    fn placeholder() -> Self {
+        Model {
+            description: None,
+            data: ModelData::Single(ModelType {
+                nullable: false,
+                type_description: ModelTypeDescription::Object(ModelObject::default()),
+            }),
+            example: None,
+            default: None,
+            read_only: false,
+            write_only: false,
+        }
+    }
+
     /// Apply context params for this object
     #[inline]
     pub fn apply_params(mut self, params: &ContextParams) -> Self {
         if let Some(description) = &params.description {
             self.description = Some(description.clone());
         }
+        if let Some(example) = &params.example {
+            self.example = Some(example.clone());
+        }
         self.data = self.data.apply_params(params);
         self
     }
@@ -670,6 +2967,11 @@ impl Model {
                 }) => self_object.merge(other_object),
                 _ => Err(ModelMergeError),
             },
+            // object with flattened fields
+            ModelData::AllOf(ModelAllOf { all_of, .. }) => match all_of.first_mut() {
+                Some(ModelReference::Inline(first)) => first.try_merge(other),
+                _ => Err(ModelMergeError),
+            },
             _ => Err(ModelMergeError),
         }
     }
@@ -678,19 +2980,67 @@ impl Model {
     fn traverse<'a>(&'a self, cx: TraverseContext<'a>) -> Result<(), &'a str> {
         self.data.traverse(cx)
     }
+
+    /// Replace links using specified renames
+    fn rename_links(&mut self, renames: &BTreeMap<String, String>) {
+        self.data.rename_links(renames)
+    }
+
+    /// Visit this model and all nested inline models
+    pub(crate) fn for_each_model_mut<F>(&mut self, f: &mut F)
+    where
+        F: FnMut(&mut Model),
+    {
+        f(self);
+
+        let references: Vec<&mut ModelReference> = match &mut self.data {
+            ModelData::Single(ModelType {
+                type_description: ModelTypeDescription::Array(array),
+                ..
+            }) => vec![array.items.as_mut()],
+            ModelData::Single(ModelType {
+                type_description: ModelTypeDescription::Object(object),
+                ..
+            }) => object
+                .properties
+                .values_mut()
+                .chain(object.additional_properties.as_deref_mut())
+                .collect(),
+            ModelData::OneOf(one_of) => one_of.one_of.iter_mut().collect(),
+            ModelData::AllOf(all_of) => all_of.all_of.iter_mut().collect(),
+            ModelData::AnyOf(any_of) => any_of.any_of.iter_mut().collect(),
+            _ => Vec::new(),
+        };
+        references
+            .into_iter()
+            .for_each(|reference| reference.for_each_model_mut(f));
+    }
+
+    /// Emit tuple items with `prefixItems`, see [`ModelArray::prefix_items`]
+    pub(crate) fn use_prefix_items(&mut self) {
+        if let ModelData::Single(ModelType {
+            type_description: ModelTypeDescription::Array(array),
+            ..
+        }) = &mut self.data
+        {
+            array.prefix_items = array.tuple;
+        }
+    }
 }
 
 #[derive(Debug, Copy, Clone)]
 pub struct ModelMergeError;
 
 /// Schema object representation
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged, rename_all = "camelCase")]
 pub enum ModelData {
+    Null(ModelNull),
     Single(ModelType),
     OneOf(ModelOneOf),
     AllOf(ModelAllOf),
     AnyOf(ModelAnyOf),
+    Any(ModelAny),
 }
 
 impl ModelData {
@@ -698,34 +3048,162 @@ impl ModelData {
     #[inline]
     pub fn apply_params(self, params: &ContextParams) -> Self {
         match self {
+            ModelData::Null(data) => ModelData::Null(data),
             ModelData::Single(data) => ModelData::Single(data.apply_params(params)),
-            data => data, // TODO: apply params to oneOf, anyOf, and allOf
+            ModelData::OneOf(mut data) => {
+                if let Some(nullable) = params.nullable {
+                    data.nullable = nullable;
+                }
+                ModelData::OneOf(data)
+            }
+            ModelData::AllOf(mut data) => {
+                if let Some(nullable) = params.nullable {
+                    data.nullable = nullable;
+                }
+                ModelData::AllOf(data)
+            }
+            ModelData::AnyOf(mut data) => {
+                if let Some(nullable) = params.nullable {
+                    data.nullable = nullable;
+                }
+                ModelData::AnyOf(data)
+            }
+            ModelData::Any(data) => ModelData::Any(data),
         }
     }
 
     /// Check links
     fn traverse<'a>(&'a self, cx: TraverseContext<'a>) -> Result<(), &'a str> {
         match self {
+            ModelData::Null(_) => Ok(()),
             ModelData::Single(single) => single.traverse(cx),
             ModelData::OneOf(one_of) => one_of.traverse(cx),
             ModelData::AllOf(all_of) => all_of.traverse(cx),
             ModelData::AnyOf(any_of) => any_of.traverse(cx),
+            ModelData::Any(_) => Ok(()),
+        }
+    }
+
+    /// Replace links using specified renames
+    fn rename_links(&mut self, renames: &BTreeMap<String, String>) {
+        match self {
+            ModelData::Null(_) => {}
+            ModelData::Single(single) => single.rename_links(renames),
+            ModelData::OneOf(one_of) => one_of.rename_links(renames),
+            ModelData::AllOf(all_of) => all_of.rename_links(renames),
+            ModelData::AnyOf(any_of) => any_of.rename_links(renames),
+            ModelData::Any(_) => {}
         }
     }
 }
 
 /// oneOf
-#[derive(Debug, Clone, Default, Serialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ModelOneOf {
     pub one_of: Vec<ModelReference>,
+
+    /// Property used to select the variant
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub discriminator: Option<ModelDiscriminator>,
+
+    /// Whether `null` is allowed in addition to the listed schemas
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub nullable: bool,
+}
+
+impl ModelOneOf {
+    /// Check links
+    fn traverse<'a>(&'a self, mut cx: TraverseContext<'a>) -> Result<(), &'a str> {
+        self.one_of.iter().try_for_each(|item| item.traverse(cx))?;
+        match &self.discriminator {
+            Some(discriminator) => discriminator
+                .mapping
+                .values()
+                .try_for_each(|link| cx.check(link)),
+            None => Ok(()),
+        }
+    }
+
+    /// Replace links using specified renames
+    fn rename_links(&mut self, renames: &BTreeMap<String, String>) {
+        self.one_of
+            .iter_mut()
+            .for_each(|item| item.rename_links(renames));
+
+        if let Some(discriminator) = &mut self.discriminator {
+            for link in discriminator.mapping.values_mut() {
+                if let Some(name) = renames.get(link) {
+                    *link = name.clone();
+                }
+            }
+        }
+    }
+}
+
+/// Discriminator Object
+///
+/// [specification](https://swagger.io/specification/#discriminator-object)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ModelDiscriminator {
+    /// Name of the property which holds the variant name
+    pub property_name: String,
+
+    /// Variant names mapped to schema names
+    #[serde(
+        default,
+        skip_serializing_if = "BTreeMap::is_empty",
+        serialize_with = "serialize_discriminator_mapping",
+        deserialize_with = "deserialize_discriminator_mapping"
+    )]
+    pub mapping: BTreeMap<String, String>,
+}
+
+impl ModelDiscriminator {
+    pub fn new<N>(property_name: N) -> Self
+    where
+        N: ToString,
+    {
+        Self {
+            property_name: property_name.to_string(),
+            mapping: BTreeMap::new(),
+        }
+    }
+}
+
+/// Serialize mapping values as schema references
+fn serialize_discriminator_mapping<S>(
+    mapping: &BTreeMap<String, String>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    let mut ser = serializer.serialize_map(Some(mapping.len()))?;
+    for (value, link) in mapping {
+        ser.serialize_entry(
+            value,
+            &format!("{}{}", crate::SCHEMA_REFERENCE_PREFIX, link),
+        )?;
+    }
+    ser.end()
 }
 
-impl ModelOneOf {
-    /// Check links
-    fn traverse<'a>(&'a self, cx: TraverseContext<'a>) -> Result<(), &'a str> {
-        self.one_of.iter().try_for_each(|item| item.traverse(cx))
+/// Deserialize mapping values, stripping schema reference prefix
+fn deserialize_discriminator_mapping<'de, D>(
+    deserializer: D,
+) -> Result<BTreeMap<String, String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let mut mapping = BTreeMap::<String, String>::deserialize(deserializer)?;
+    for link in mapping.values_mut() {
+        if let Some(name) = link.strip_prefix(crate::SCHEMA_REFERENCE_PREFIX) {
+            *link = name.to_owned();
+        }
     }
+    Ok(mapping)
 }
 
 impl From<ModelOneOf> for ModelData {
@@ -735,10 +3213,14 @@ impl From<ModelOneOf> for ModelData {
 }
 
 /// allOf
-#[derive(Debug, Clone, Default, Serialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ModelAllOf {
     pub all_of: Vec<ModelReference>,
+
+    /// Whether `null` is allowed in addition to the listed schemas
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub nullable: bool,
 }
 
 impl ModelAllOf {
@@ -746,6 +3228,13 @@ impl ModelAllOf {
     fn traverse<'a>(&'a self, cx: TraverseContext<'a>) -> Result<(), &'a str> {
         self.all_of.iter().try_for_each(|item| item.traverse(cx))
     }
+
+    /// Replace links using specified renames
+    fn rename_links(&mut self, renames: &BTreeMap<String, String>) {
+        self.all_of
+            .iter_mut()
+            .for_each(|item| item.rename_links(renames))
+    }
 }
 
 impl From<ModelAllOf> for ModelData {
@@ -755,10 +3244,14 @@ impl From<ModelAllOf> for ModelData {
 }
 
 /// anyOf
-#[derive(Debug, Clone, Default, Serialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ModelAnyOf {
     pub any_of: Vec<ModelReference>,
+
+    /// Whether `null` is allowed in addition to the listed schemas
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub nullable: bool,
 }
 
 impl ModelAnyOf {
@@ -766,6 +3259,13 @@ impl ModelAnyOf {
     fn traverse<'a>(&'a self, cx: TraverseContext<'a>) -> Result<(), &'a str> {
         self.any_of.iter().try_for_each(|item| item.traverse(cx))
     }
+
+    /// Replace links using specified renames
+    fn rename_links(&mut self, renames: &BTreeMap<String, String>) {
+        self.any_of
+            .iter_mut()
+            .for_each(|item| item.rename_links(renames))
+    }
 }
 
 impl From<ModelAnyOf> for ModelData {
@@ -774,12 +3274,91 @@ impl From<ModelAnyOf> for ModelData {
     }
 }
 
+/// Schema which only allows `null`
+///
+/// Represented as `nullable` enum with the only `null` variant, because there is
+/// no `null` type before OpenAPI 3.1
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ModelNull;
+
+impl Serialize for ModelNull {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut map = serializer.serialize_map(Some(2))?;
+        map.serialize_entry("nullable", &true)?;
+        map.serialize_entry("enum", &[()])?;
+        map.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for ModelNull {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(deny_unknown_fields)]
+        struct NullRepr {
+            nullable: bool,
+            #[serde(rename = "enum")]
+            _variants: [(); 1],
+        }
+
+        match NullRepr::deserialize(deserializer)? {
+            NullRepr { nullable: true, .. } => Ok(ModelNull),
+            _ => Err(de::Error::custom("null schema must be nullable")),
+        }
+    }
+}
+
+impl From<ModelNull> for ModelData {
+    fn from(data: ModelNull) -> Self {
+        ModelData::Null(data)
+    }
+}
+
+/// Schema which allows any value
+///
+/// Unlike [`ModelReference::Any`], it is a model, so it can hold description and example
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ModelAny;
+
+impl Serialize for ModelAny {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_map(Some(0))?.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for ModelAny {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(deny_unknown_fields)]
+        struct AnyRepr {}
+
+        AnyRepr::deserialize(deserializer).map(|_| ModelAny)
+    }
+}
+
+impl From<ModelAny> for ModelData {
+    fn from(data: ModelAny) -> Self {
+        ModelData::Any(data)
+    }
+}
+
 /// type
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ModelType {
     /// Whether this type can have `null` value
-    #[serde(skip_serializing_if = "is_false")]
+    #[serde(default, skip_serializing_if = "is_false")]
     pub nullable: bool,
 
     /// Type description
@@ -806,6 +3385,87 @@ impl ModelType {
             _ => Ok(()),
         }
     }
+
+    /// Combine this object with schemas of `#[serde(flatten)]` fields
+    ///
+    /// Inline objects are merged into properties and inline dictionaries become additional
+    /// properties. Links and other schemas (e.g. enums) are combined using `allOf`.
+    pub fn flatten(mut self, flattened: Vec<ModelReference>) -> ModelData {
+        let mut all_of = Vec::new();
+
+        for reference in flattened {
+            let model = match reference {
+                ModelReference::Inline(model) => model,
+                reference => {
+                    all_of.push(reference);
+                    continue;
+                }
+            };
+
+            let (nullable, other) = match (&mut self.type_description, model.data) {
+                (
+                    ModelTypeDescription::Object(object),
+                    ModelData::Single(ModelType {
+                        nullable,
+                        type_description: ModelTypeDescription::Object(other),
+                    }),
+                ) if object.additional_properties.is_none()
+                    || other.additional_properties.is_none() =>
+                {
+                    (nullable, other)
+                }
+                (_, data) => {
+                    all_of.push(ModelReference::Inline(Model { data, ..model }));
+                    continue;
+                }
+            };
+
+            if let ModelTypeDescription::Object(object) = &mut self.type_description {
+                for (property, property_model) in other.properties {
+                    if let Entry::Vacant(entry) = object.properties.entry(property.clone()) {
+                        entry.insert(property_model);
+                        if !nullable && other.required.contains(&property) {
+                            object.required.push(property);
+                        }
+                    }
+                }
+
+                if other.additional_properties.is_some() {
+                    object.additional_properties = other.additional_properties;
+                }
+            }
+        }
+
+        if all_of.is_empty() {
+            return ModelData::Single(self);
+        }
+
+        all_of.insert(
+            0,
+            ModelReference::Inline(Model {
+                description: None,
+                data: ModelData::Single(self),
+                example: None,
+                default: None,
+                read_only: false,
+                write_only: false,
+            }),
+        );
+
+        ModelData::AllOf(ModelAllOf {
+            all_of,
+            nullable: false,
+        })
+    }
+
+    /// Replace links using specified renames
+    fn rename_links(&mut self, renames: &BTreeMap<String, String>) {
+        match &mut self.type_description {
+            ModelTypeDescription::Array(array) => array.items.rename_links(renames),
+            ModelTypeDescription::Object(object) => object.rename_links(renames),
+            _ => {}
+        }
+    }
 }
 
 impl From<ModelType> for ModelData {
@@ -817,7 +3477,7 @@ impl From<ModelType> for ModelData {
 /// Data Type
 ///
 /// [specification](https://swagger.io/specification/#data-types)
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(tag = "type", rename_all = "camelCase")]
 pub enum ModelTypeDescription {
     String(ModelString),
@@ -842,19 +3502,38 @@ impl ModelTypeDescription {
             ModelTypeDescription::Integer(integer) => {
                 ModelTypeDescription::Integer(integer.apply_params(params))
             }
+            ModelTypeDescription::Array(array) => {
+                ModelTypeDescription::Array(array.apply_params(params))
+            }
+            ModelTypeDescription::Object(object) => {
+                ModelTypeDescription::Object(object.apply_params(params))
+            }
             other => other,
         }
     }
 }
 
 /// String data type
-#[derive(Debug, Clone, Default, Serialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
 #[serde(rename_all = "camelCase")]
 pub struct ModelString {
     /// Possible values
     #[serde(rename = "enum", skip_serializing_if = "Option::is_none")]
     pub variants: Option<Vec<String>>,
 
+    /// Minimal string length
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_length: Option<usize>,
+
+    /// Maximal string length
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_length: Option<usize>,
+
+    /// Regular expression which the string must match
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pattern: Option<String>,
+
     /// Other type description data
     #[serde(flatten)]
     pub data: ModelSimple,
@@ -867,6 +3546,15 @@ impl ModelString {
         if let Some(variants) = &params.variants {
             self.variants = Some(variants.clone());
         }
+        if let Some(min_length) = params.min_length {
+            self.min_length = Some(min_length);
+        }
+        if let Some(max_length) = params.max_length {
+            self.max_length = Some(max_length);
+        }
+        if let Some(pattern) = &params.pattern {
+            self.pattern = Some(pattern.clone());
+        }
         self.data = self.data.apply_params(params);
         self
     }
@@ -879,16 +3567,33 @@ impl From<ModelString> for ModelTypeDescription {
 }
 
 /// Simple model type description
-#[derive(Debug, Clone, Default, Serialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
 #[serde(rename_all = "camelCase")]
 pub struct ModelSimple {
     /// Value format
     #[serde(skip_serializing_if = "Option::is_none")]
     pub format: Option<String>,
 
-    /// Example value
+    /// Minimal numeric value
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub minimum: Option<serde_json::Number>,
+
+    /// Maximal numeric value
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub example: Option<String>,
+    pub maximum: Option<serde_json::Number>,
+
+    /// Whether the value must be strictly greater than `minimum`
+    #[serde(skip_serializing_if = "is_false")]
+    pub exclusive_minimum: bool,
+
+    /// Whether the value must be strictly less than `maximum`
+    #[serde(skip_serializing_if = "is_false")]
+    pub exclusive_maximum: bool,
+
+    /// Numeric value divisor
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub multiple_of: Option<serde_json::Number>,
 }
 
 impl ModelSimple {
@@ -898,21 +3603,125 @@ impl ModelSimple {
         if let Some(format) = &params.format {
             self.format = Some(format.clone());
         }
-        if let Some(example) = &params.example {
-            self.example = Some(example.clone());
+        if let Some(minimum) = &params.minimum {
+            self.minimum = Some(minimum.clone());
+        }
+        if let Some(maximum) = &params.maximum {
+            self.maximum = Some(maximum.clone());
+        }
+        if let Some(exclusive_minimum) = params.exclusive_minimum {
+            self.exclusive_minimum = exclusive_minimum;
+        }
+        if let Some(exclusive_maximum) = params.exclusive_maximum {
+            self.exclusive_maximum = exclusive_maximum;
+        }
+        if let Some(multiple_of) = &params.multiple_of {
+            self.multiple_of = Some(multiple_of.clone());
         }
         self
     }
 }
 
 /// Array type description
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ModelArray {
     pub items: Box<ModelReference>,
+
+    /// Minimal number of items
+    pub min_items: Option<usize>,
+
+    /// Maximal number of items
+    pub max_items: Option<usize>,
+
+    /// Whether all items must be unique
+    #[serde(default)]
+    pub unique_items: bool,
+
+    /// Whether this array is a tuple, `items` is expected to be `oneOf` of the positional items.
+    #[serde(skip)]
+    pub tuple: bool,
+
+    /// Whether positional items of the tuple are emitted as `prefixItems` of JSON Schema 2020-12
+    ///
+    /// Set for all tuples while serializing OpenAPI 3.1
+    #[serde(skip)]
+    pub prefix_items: bool,
+}
+
+impl Default for ModelArray {
+    fn default() -> Self {
+        Self {
+            items: Box::new(ModelReference::Any),
+            min_items: None,
+            max_items: None,
+            unique_items: false,
+            tuple: false,
+            prefix_items: false,
+        }
+    }
+}
+
+impl Serialize for ModelArray {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let prefix_items = match &*self.items {
+            ModelReference::Inline(Model {
+                data: ModelData::OneOf(ModelOneOf { one_of, .. }),
+                ..
+            }) if self.tuple && self.prefix_items => Some(one_of),
+            _ => None,
+        };
+        let min_items = match prefix_items {
+            Some(prefix_items) => self.min_items.or(Some(prefix_items.len())),
+            None => self.min_items,
+        };
+
+        let len = 1
+            + prefix_items.is_some() as usize
+            + min_items.is_some() as usize
+            + self.max_items.is_some() as usize
+            + self.unique_items as usize;
+
+        let mut ser = serializer.serialize_struct("ModelArray", len)?;
+        match prefix_items {
+            Some(prefix_items) => {
+                ser.serialize_field("items", &false)?;
+                ser.serialize_field("prefixItems", prefix_items)?;
+            }
+            None => ser.serialize_field("items", &self.items)?,
+        }
+        if let Some(min_items) = &min_items {
+            ser.serialize_field("minItems", min_items)?;
+        }
+        if let Some(max_items) = &self.max_items {
+            ser.serialize_field("maxItems", max_items)?;
+        }
+        if self.unique_items {
+            ser.serialize_field("uniqueItems", &true)?;
+        }
+        ser.end()
+    }
 }
 
 impl ModelArray {
+    /// Apply context params for this object
+    #[inline]
+    pub fn apply_params(mut self, params: &ContextParams) -> Self {
+        if let Some(min_items) = params.min_items {
+            self.min_items = Some(min_items);
+        }
+        if let Some(max_items) = params.max_items {
+            self.max_items = Some(max_items);
+        }
+        if let Some(unique_items) = params.unique_items {
+            self.unique_items = unique_items;
+        }
+        self
+    }
+
     /// Check links
     fn traverse<'a>(&'a self, cx: TraverseContext<'a>) -> Result<(), &'a str> {
         self.items.traverse(cx)
@@ -926,7 +3735,8 @@ impl From<ModelArray> for ModelTypeDescription {
 }
 
 /// Object type description
-#[derive(Debug, Clone, Default, Serialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
 #[serde(rename_all = "camelCase")]
 pub struct ModelObject {
     /// Object properties
@@ -934,15 +3744,38 @@ pub struct ModelObject {
     pub properties: BTreeMap<String, ModelReference>,
 
     /// Additional properties
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "deserialize_additional_properties"
+    )]
     pub additional_properties: Option<Box<ModelReference>>,
 
     /// Required properties
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub required: Vec<String>,
+
+    /// Minimal number of properties
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_properties: Option<usize>,
+
+    /// Maximal number of properties
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_properties: Option<usize>,
 }
 
 impl ModelObject {
+    /// Apply context params for this object
+    #[inline]
+    pub fn apply_params(mut self, params: &ContextParams) -> Self {
+        if let Some(min_properties) = params.min_properties {
+            self.min_properties = Some(min_properties);
+        }
+        if let Some(max_properties) = params.max_properties {
+            self.max_properties = Some(max_properties);
+        }
+        self
+    }
+
     /// Manually add property
     pub fn add_property(
         &mut self,
@@ -994,6 +3827,18 @@ impl ModelObject {
             .chain(self.additional_properties.iter().map(|item| item.as_ref()))
             .try_for_each(|reference| reference.traverse(cx))
     }
+
+    /// Replace links using specified renames
+    fn rename_links(&mut self, renames: &BTreeMap<String, String>) {
+        self.properties
+            .values_mut()
+            .chain(
+                self.additional_properties
+                    .iter_mut()
+                    .map(|item| item.as_mut()),
+            )
+            .for_each(|reference| reference.rename_links(renames))
+    }
 }
 
 impl From<ModelObject> for ModelTypeDescription {
@@ -1005,6 +3850,7 @@ impl From<ModelObject> for ModelTypeDescription {
 /// Model reference
 #[derive(Debug, Clone, Serialize)]
 #[serde(untagged)]
+#[allow(clippy::large_enum_variant)]
 pub enum ModelReference {
     /// `$ref: "#/components/schemas/..."`
     #[serde(serialize_with = "serialize_model_reference_link")]
@@ -1040,7 +3886,130 @@ where
     serializer.serialize_struct("Any", 0)?.end()
 }
 
+impl<'de> Deserialize<'de> for ModelReference {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        ModelReference::from_value(serde_json::Value::deserialize(deserializer)?)
+            .map_err(de::Error::custom)
+    }
+}
+
+/// Deserialize additional properties, treating `false` as absent
+fn deserialize_additional_properties<'de, D>(
+    deserializer: D,
+) -> Result<Option<Box<ModelReference>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    match serde_json::Value::deserialize(deserializer)? {
+        serde_json::Value::Bool(false) | serde_json::Value::Null => Ok(None),
+        value => ModelReference::from_value(value)
+            .map(|reference| Some(Box::new(reference)))
+            .map_err(de::Error::custom),
+    }
+}
+
 impl ModelReference {
+    /// Visit inline model and all nested inline models
+    pub(crate) fn for_each_model_mut<F>(&mut self, f: &mut F)
+    where
+        F: FnMut(&mut Model),
+    {
+        if let ModelReference::Inline(model) = self {
+            model.for_each_model_mut(f);
+        }
+    }
+
+    /// Mark referenced value as only sent in responses
+    pub fn into_read_only(self) -> Self {
+        let mut model = self.into_model();
+        model.read_only = true;
+        ModelReference::Inline(model)
+    }
+
+    /// Mark referenced value as only accepted in requests
+    pub fn into_write_only(self) -> Self {
+        let mut model = self.into_model();
+        model.write_only = true;
+        ModelReference::Inline(model)
+    }
+
+    /// Set value used when this one is omitted
+    pub fn with_default(self, default: Option<serde_json::Value>) -> Self {
+        match default {
+            Some(default) => {
+                let mut model = self.into_model();
+                model.default = Some(default);
+                ModelReference::Inline(model)
+            }
+            None => self,
+        }
+    }
+
+    /// Apply context params, wrapping links into `allOf` when params need sibling keywords
+    pub fn apply_params(self, params: &ContextParams) -> Self {
+        match self {
+            ModelReference::Inline(model) => ModelReference::Inline(model.apply_params(params)),
+            // `$ref` siblings are ignored, so the link is wrapped into `allOf`
+            ModelReference::Link(_) if params.has_reference_siblings() => {
+                ModelReference::Inline(self.into_model().apply_params(params))
+            }
+            // `{}` has no model to hold the params, so it is replaced with an empty model
+            ModelReference::Any if params.has_reference_siblings() => {
+                ModelReference::Inline(self.into_model().apply_params(params))
+            }
+            reference => reference,
+        }
+    }
+
+    /// Get inline model, wrapping links into `allOf` to allow sibling keywords
+    pub fn into_model(self) -> Model {
+        match self {
+            ModelReference::Inline(model) => model,
+            ModelReference::Any => Model {
+                description: None,
+                data: ModelData::Any(ModelAny),
+                example: None,
+                default: None,
+                read_only: false,
+                write_only: false,
+            },
+            reference => Model {
+                description: None,
+                data: ModelData::AllOf(ModelAllOf {
+                    all_of: vec![reference],
+                    nullable: false,
+                }),
+                example: None,
+                default: None,
+                read_only: false,
+                write_only: false,
+            },
+        }
+    }
+
+    /// Parse schema or reference from JSON value
+    fn from_value(value: serde_json::Value) -> Result<Self, String> {
+        match value {
+            serde_json::Value::Bool(true) => Ok(ModelReference::Any),
+            serde_json::Value::Object(object) if object.is_empty() => Ok(ModelReference::Any),
+            serde_json::Value::Object(object) if object.contains_key("$ref") => {
+                match object["$ref"].as_str() {
+                    Some(link) => link
+                        .strip_prefix(crate::SCHEMA_REFERENCE_PREFIX)
+                        .map(|name| ModelReference::Link(name.to_owned()))
+                        .ok_or_else(|| format!("unsupported schema reference `{}`", link)),
+                    None => Err("schema reference must be a string".to_owned()),
+                }
+            }
+            value => serde_json::from_value(value)
+                .map(ModelReference::Inline)
+                .map_err(|e| e.to_string()),
+        }
+    }
+
     /// Check links
     fn traverse<'a>(&'a self, mut cx: TraverseContext<'a>) -> Result<(), &'a str> {
         match &self {
@@ -1048,6 +4017,19 @@ impl ModelReference {
             _ => Ok(()),
         }
     }
+
+    /// Replace links using specified renames
+    fn rename_links(&mut self, renames: &BTreeMap<String, String>) {
+        match self {
+            ModelReference::Link(link) => {
+                if let Some(name) = renames.get(link) {
+                    *link = name.clone();
+                }
+            }
+            ModelReference::Inline(model) => model.rename_links(renames),
+            ModelReference::Any => {}
+        }
+    }
 }
 
 #[derive(Copy, Clone)]
@@ -1066,16 +4048,19 @@ impl<'a> TraverseContext<'a> {
 /// Security Scheme Object
 ///
 /// [specification](https://swagger.io/specification/#security-scheme-object)
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase", tag = "type")]
+#[allow(clippy::large_enum_variant)]
 pub enum SecurityScheme {
     Http(HttpSecurityScheme),
     ApiKey(ApiKeySecurityScheme),
-    // TODO: add `oath2` and `openIdConnect`
+    #[serde(rename = "oauth2")]
+    OAuth2(OAuth2SecurityScheme),
+    OpenIdConnect(OpenIdConnectSecurityScheme),
 }
 
 /// HTTP security scheme
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase", tag = "scheme")]
 pub enum HttpSecurityScheme {
     Basic {
@@ -1105,7 +4090,7 @@ pub enum HttpSecuritySchemeKind {
 }
 
 /// Api key security scheme
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ApiKeySecurityScheme {
     /// The location of the API key
@@ -1126,5 +4111,103 @@ impl From<ApiKeySecurityScheme> for SecurityScheme {
     }
 }
 
+/// OAuth2 security scheme
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct OAuth2SecurityScheme {
+    /// Configuration information for the supported flow types
+    pub flows: OAuthFlows,
+
+    /// A short description for security scheme
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+impl From<OAuth2SecurityScheme> for SecurityScheme {
+    fn from(data: OAuth2SecurityScheme) -> Self {
+        SecurityScheme::OAuth2(data)
+    }
+}
+
+/// OAuth Flows Object
+///
+/// [specification](https://swagger.io/specification/#oauth-flows-object)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+#[serde(rename_all = "camelCase")]
+pub struct OAuthFlows {
+    /// Configuration for the OAuth Implicit flow
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub implicit: Option<OAuthFlow>,
+
+    /// Configuration for the OAuth Resource Owner Password flow
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub password: Option<OAuthFlow>,
+
+    /// Configuration for the OAuth Client Credentials flow
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_credentials: Option<OAuthFlow>,
+
+    /// Configuration for the OAuth Authorization Code flow
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub authorization_code: Option<OAuthFlow>,
+}
+
+impl OAuthFlows {
+    /// Check whether any of the flows declares the scope
+    pub fn contains_scope(&self, scope: &str) -> bool {
+        [
+            &self.implicit,
+            &self.password,
+            &self.client_credentials,
+            &self.authorization_code,
+        ]
+        .iter()
+        .filter_map(|flow| flow.as_ref())
+        .any(|flow| flow.scopes.contains_key(scope))
+    }
+}
+
+/// OAuth Flow Object
+///
+/// [specification](https://swagger.io/specification/#oauth-flow-object)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+#[serde(rename_all = "camelCase")]
+pub struct OAuthFlow {
+    /// The authorization URL. Required for `implicit` and `authorizationCode` flows
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub authorization_url: Option<String>,
+
+    /// The token URL. Required for `password`, `clientCredentials` and `authorizationCode` flows
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token_url: Option<String>,
+
+    /// The URL to be used for obtaining refresh tokens
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub refresh_url: Option<String>,
+
+    /// The available scopes with their short descriptions
+    pub scopes: BTreeMap<String, String>,
+}
+
+/// OpenID Connect security scheme
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OpenIdConnectSecurityScheme {
+    /// OpenId Connect URL to discover OAuth2 configuration values
+    pub open_id_connect_url: String,
+
+    /// A short description for security scheme
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+impl From<OpenIdConnectSecurityScheme> for SecurityScheme {
+    fn from(data: OpenIdConnectSecurityScheme) -> Self {
+        SecurityScheme::OpenIdConnect(data)
+    }
+}
+
 /// Stub for macros
 pub struct ParameterNotSpecified;
@@ -0,0 +1,161 @@
+//! Schemas for the serde adapters of date and time crates
+//!
+//! Paths mirror the adapters, so `#[serde(with = "chrono::serde::ts_seconds")]` is paired
+//! with `#[opg(schema_with = "opg::schema_with::chrono::ts_seconds")]`
+
+use crate::models::*;
+
+fn unix_timestamp(unit: &str, nullable: bool) -> ModelReference {
+    ModelReference::Inline(Model {
+        description: Some(format!("Unix timestamp in {}", unit)),
+        example: None,
+        default: None,
+        read_only: false,
+        write_only: false,
+        data: ModelData::Single(ModelType {
+            nullable,
+            type_description: ModelTypeDescription::Integer(ModelSimple {
+                format: Some("int64".to_owned()),
+                ..Default::default()
+            }),
+        }),
+    })
+}
+
+#[cfg(feature = "time")]
+fn formatted_datetime(
+    description: &str,
+    format: Option<&str>,
+    example: &str,
+    nullable: bool,
+) -> ModelReference {
+    ModelReference::Inline(Model {
+        description: Some(description.to_owned()),
+        example: Some(example.into()),
+        default: None,
+        read_only: false,
+        write_only: false,
+        data: ModelData::Single(ModelType {
+            nullable,
+            type_description: ModelTypeDescription::String(ModelString {
+                variants: None,
+                data: ModelSimple {
+                    format: format.map(str::to_owned),
+                    ..Default::default()
+                },
+                ..Default::default()
+            }),
+        }),
+    })
+}
+
+/// Schemas for the adapters from `chrono::serde` and `chrono::naive::serde`
+#[cfg(feature = "chrono")]
+pub mod chrono {
+    use super::unix_timestamp;
+    use crate::models::{Components, ModelReference};
+
+    macro_rules! ts_schemas {
+        ($($name:ident, $option_name:ident => $unit:literal;)*) => {
+            $(
+                pub fn $name(_: &mut Components) -> ModelReference {
+                    unix_timestamp($unit, false)
+                }
+
+                pub fn $option_name(_: &mut Components) -> ModelReference {
+                    unix_timestamp($unit, true)
+                }
+            )*
+        };
+    }
+
+    ts_schemas! {
+        ts_seconds, ts_seconds_option => "seconds";
+        ts_milliseconds, ts_milliseconds_option => "milliseconds";
+        ts_microseconds, ts_microseconds_option => "microseconds";
+        ts_nanoseconds, ts_nanoseconds_option => "nanoseconds";
+    }
+}
+
+/// Schemas for the adapters from `time::serde`
+#[cfg(feature = "time")]
+pub mod time {
+    use super::{formatted_datetime, unix_timestamp};
+    use crate::models::{Components, ModelReference};
+
+    const RFC3339_DESCRIPTION: &str = "Datetime in RFC 3339 format";
+    const RFC3339_EXAMPLE: &str = "2020-06-26T14:04:20.730045106Z";
+
+    const RFC2822_DESCRIPTION: &str = "Datetime in RFC 2822 format";
+    const RFC2822_EXAMPLE: &str = "Fri, 26 Jun 2020 14:04:20 +0000";
+
+    pub fn rfc3339(_: &mut Components) -> ModelReference {
+        formatted_datetime(
+            RFC3339_DESCRIPTION,
+            Some("date-time"),
+            RFC3339_EXAMPLE,
+            false,
+        )
+    }
+
+    pub mod rfc3339 {
+        use super::*;
+
+        pub fn option(_: &mut Components) -> ModelReference {
+            formatted_datetime(
+                RFC3339_DESCRIPTION,
+                Some("date-time"),
+                RFC3339_EXAMPLE,
+                true,
+            )
+        }
+    }
+
+    pub fn rfc2822(_: &mut Components) -> ModelReference {
+        formatted_datetime(RFC2822_DESCRIPTION, None, RFC2822_EXAMPLE, false)
+    }
+
+    pub mod rfc2822 {
+        use super::*;
+
+        pub fn option(_: &mut Components) -> ModelReference {
+            formatted_datetime(RFC2822_DESCRIPTION, None, RFC2822_EXAMPLE, true)
+        }
+    }
+
+    pub fn timestamp(_: &mut Components) -> ModelReference {
+        unix_timestamp("seconds", false)
+    }
+
+    pub mod timestamp {
+        use super::*;
+
+        macro_rules! timestamp_schemas {
+            ($($name:ident => $unit:literal;)*) => {
+                pub fn option(_: &mut Components) -> ModelReference {
+                    unix_timestamp("seconds", true)
+                }
+
+                $(
+                    pub fn $name(_: &mut Components) -> ModelReference {
+                        unix_timestamp($unit, false)
+                    }
+
+                    pub mod $name {
+                        use super::*;
+
+                        pub fn option(_: &mut Components) -> ModelReference {
+                            unix_timestamp($unit, true)
+                        }
+                    }
+                )*
+            };
+        }
+
+        timestamp_schemas! {
+            milliseconds => "milliseconds";
+            microseconds => "microseconds";
+            nanoseconds => "nanoseconds";
+        }
+    }
+}
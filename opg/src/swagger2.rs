@@ -0,0 +1,666 @@
+use crate::models::{escape_pointer, OpenApiDialect, Opg};
+use serde_json::{json, to_value, Map, Value};
+
+pub const SWAGGER_VERSION: &str = "2.0";
+pub const DEFINITIONS_REFERENCE_PREFIX: &str = "#/definitions/";
+
+/// Swagger 2.0 document converted from `Opg`
+#[derive(Debug, Clone)]
+pub struct Swagger2Document {
+    /// Converted document
+    pub document: Value,
+
+    /// Constructs which have no Swagger 2.0 equivalent and were dropped or changed
+    pub warnings: Vec<Swagger2Warning>,
+}
+
+/// Swagger 2.0 conversion problem
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Swagger2Warning {
+    /// JSON pointer to the converted part of the OpenAPI 3.0 document
+    pub location: String,
+
+    /// Problem description
+    pub kind: Swagger2WarningKind,
+}
+
+impl std::fmt::Display for Swagger2Warning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.location, self.kind)
+    }
+}
+
+/// Kind of Swagger 2.0 conversion problem
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Swagger2WarningKind {
+    /// Only the first server is used for `host`, `basePath` and `schemes`
+    MultipleServers,
+
+    /// `oneOf` or `anyOf` schema composition was dropped
+    UnsupportedComposition(&'static str),
+
+    /// Callbacks were dropped
+    Callbacks,
+
+    /// Response links were dropped
+    Links,
+
+    /// Webhooks were dropped
+    Webhooks,
+
+    /// Path item `summary` or `description` was dropped
+    PathItemField(&'static str),
+
+    /// Named media type examples were dropped
+    Examples,
+
+    /// Media type encoding was dropped
+    Encoding,
+
+    /// Cookie parameter was dropped
+    CookieParameter(String),
+
+    /// Parameter or header schema is not a primitive type or an array
+    ComplexParameter(String),
+
+    /// Only the schema of the first media type is used
+    MultipleMediaTypes,
+
+    /// Security scheme was dropped
+    UnsupportedSecurityScheme(String),
+
+    /// Bearer authentication was replaced with the `Authorization` header API key
+    BearerAuth(String),
+
+    /// Only the first OAuth2 flow is used
+    MultipleOAuthFlows(String),
+}
+
+impl std::fmt::Display for Swagger2WarningKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Swagger2WarningKind::MultipleServers => f.write_str("only the first server is used"),
+            Swagger2WarningKind::UnsupportedComposition(keyword) => {
+                write!(f, "`{}` is not supported and was dropped", keyword)
+            }
+            Swagger2WarningKind::Callbacks => f.write_str("callbacks are not supported"),
+            Swagger2WarningKind::Links => f.write_str("links are not supported"),
+            Swagger2WarningKind::Webhooks => f.write_str("webhooks are not supported"),
+            Swagger2WarningKind::PathItemField(field) => {
+                write!(f, "path item `{}` is not supported", field)
+            }
+            Swagger2WarningKind::Examples => f.write_str("media type examples are not supported"),
+            Swagger2WarningKind::Encoding => f.write_str("encoding is not supported"),
+            Swagger2WarningKind::CookieParameter(name) => {
+                write!(f, "cookie parameter `{}` is not supported", name)
+            }
+            Swagger2WarningKind::ComplexParameter(name) => {
+                write!(
+                    f,
+                    "schema of `{}` must be a primitive type or an array",
+                    name
+                )
+            }
+            Swagger2WarningKind::MultipleMediaTypes => {
+                f.write_str("only the schema of the first media type is used")
+            }
+            Swagger2WarningKind::UnsupportedSecurityScheme(name) => {
+                write!(f, "security scheme `{}` is not supported", name)
+            }
+            Swagger2WarningKind::BearerAuth(name) => write!(
+                f,
+                "bearer security scheme `{}` is replaced with `Authorization` header",
+                name
+            ),
+            Swagger2WarningKind::MultipleOAuthFlows(name) => {
+                write!(f, "only the first flow of `{}` is used", name)
+            }
+        }
+    }
+}
+
+impl Opg {
+    /// Convert this document into Swagger 2.0
+    ///
+    /// Constructs without Swagger 2.0 equivalent are reported as warnings
+    pub fn to_swagger2(&self) -> Swagger2Document {
+        let source = if self.dialect == OpenApiDialect::OpenApi30 {
+            to_value(self)
+        } else {
+            let mut source = self.clone();
+            source.dialect = OpenApiDialect::OpenApi30;
+            to_value(&source)
+        }
+        .expect("document must be serializable into JSON");
+
+        let mut cx = ConversionContext::default();
+        if let Some(Value::Object(schemas)) = source.pointer("/components/schemas") {
+            cx.schemas = schemas.clone();
+        }
+        let document = cx.convert_document(source);
+
+        Swagger2Document {
+            document,
+            warnings: cx.warnings,
+        }
+    }
+}
+
+#[derive(Default)]
+struct ConversionContext {
+    warnings: Vec<Swagger2Warning>,
+    /// Source schemas for inlining references in parameters
+    schemas: Map<String, Value>,
+}
+
+impl ConversionContext {
+    fn warn(&mut self, location: String, kind: Swagger2WarningKind) {
+        self.warnings.push(Swagger2Warning { location, kind });
+    }
+
+    fn convert_document(&mut self, mut source: Value) -> Value {
+        let mut document = Map::new();
+        document.insert("swagger".to_owned(), SWAGGER_VERSION.into());
+        document.insert("info".to_owned(), source["info"].take());
+
+        if let Some(Value::Array(servers)) = source.get_mut("servers") {
+            if servers.len() > 1 {
+                self.warn("/servers".to_owned(), Swagger2WarningKind::MultipleServers);
+            }
+            if let Some(server) = servers.first() {
+                convert_server(server, &mut document);
+            }
+        }
+
+        if let Some(tags) = source.get_mut("tags") {
+            document.insert("tags".to_owned(), tags.take());
+        }
+
+        let mut paths = Map::new();
+        if let Some(Value::Object(source_paths)) = source.get_mut("paths") {
+            for (path, path_item) in source_paths.iter_mut() {
+                let location = format!("/paths/{}", escape_pointer(path));
+                paths.insert(path.clone(), self.convert_path_item(path_item, &location));
+            }
+        }
+        document.insert("paths".to_owned(), Value::Object(paths));
+
+        if source.get("webhooks").is_some() {
+            self.warn("/webhooks".to_owned(), Swagger2WarningKind::Webhooks);
+        }
+
+        if let Some(Value::Object(schemas)) = source.pointer_mut("/components/schemas") {
+            let definitions = schemas
+                .iter_mut()
+                .map(|(name, schema)| {
+                    let location = format!("/components/schemas/{}", escape_pointer(name));
+                    self.convert_schema(schema, &location);
+                    (name.clone(), schema.take())
+                })
+                .collect();
+            document.insert("definitions".to_owned(), Value::Object(definitions));
+        }
+
+        if let Some(Value::Object(schemes)) = source.pointer_mut("/components/securitySchemes") {
+            let definitions = schemes
+                .iter_mut()
+                .filter_map(|(name, scheme)| {
+                    let location = format!("/components/securitySchemes/{}", escape_pointer(name));
+                    self.convert_security_scheme(name, scheme, &location)
+                        .map(|scheme| (name.clone(), scheme))
+                })
+                .collect();
+            document.insert("securityDefinitions".to_owned(), Value::Object(definitions));
+        }
+
+        Value::Object(document)
+    }
+
+    fn convert_path_item(&mut self, path_item: &mut Value, location: &str) -> Value {
+        let mut result = Map::new();
+
+        let path_item = match path_item.as_object_mut() {
+            Some(path_item) => path_item,
+            None => return Value::Object(result),
+        };
+
+        for (key, value) in path_item.iter_mut() {
+            let value_location = format!("{}/{}", location, key);
+            let value = match key.as_str() {
+                "parameters" => self.convert_parameters(value, &value_location),
+                "summary" => {
+                    self.warn(
+                        value_location,
+                        Swagger2WarningKind::PathItemField("summary"),
+                    );
+                    continue;
+                }
+                "description" => {
+                    self.warn(
+                        value_location,
+                        Swagger2WarningKind::PathItemField("description"),
+                    );
+                    continue;
+                }
+                _ => self.convert_operation(value, &value_location),
+            };
+            result.insert(key.clone(), value);
+        }
+
+        Value::Object(result)
+    }
+
+    fn convert_operation(&mut self, operation: &mut Value, location: &str) -> Value {
+        let mut result = Map::new();
+
+        let operation = match operation.as_object_mut() {
+            Some(operation) => operation,
+            None => return Value::Object(result),
+        };
+
+        let mut parameters = match operation.get_mut("parameters") {
+            Some(parameters) => {
+                match self.convert_parameters(parameters, &format!("{}/parameters", location)) {
+                    Value::Array(parameters) => parameters,
+                    _ => Vec::new(),
+                }
+            }
+            None => Vec::new(),
+        };
+
+        for (key, value) in operation.iter_mut() {
+            let value_location = format!("{}/{}", location, key);
+            match key.as_str() {
+                "parameters" => {}
+                "requestBody" => {
+                    if let Some(Value::Object(content)) = value.get_mut("content") {
+                        result.insert(
+                            "consumes".to_owned(),
+                            content.keys().cloned().collect::<Vec<_>>().into(),
+                        );
+                    }
+
+                    let mut body = Map::new();
+                    body.insert("name".to_owned(), "body".into());
+                    body.insert("in".to_owned(), "body".into());
+                    if let Some(Value::String(description)) = value.get("description") {
+                        if !description.is_empty() {
+                            body.insert("description".to_owned(), description.clone().into());
+                        }
+                    }
+                    if let Some(required) = value.get("required") {
+                        body.insert("required".to_owned(), required.clone());
+                    }
+                    let schema = self.convert_content(value, &value_location);
+                    body.insert(
+                        "schema".to_owned(),
+                        schema.unwrap_or_else(|| Value::Object(Map::new())),
+                    );
+                    parameters.push(Value::Object(body));
+                }
+                "responses" => {
+                    let mut produces = Vec::new();
+                    let responses = self.convert_responses(value, &value_location, &mut produces);
+                    if !produces.is_empty() {
+                        result.insert("produces".to_owned(), produces.into());
+                    }
+                    result.insert(key.clone(), responses);
+                }
+                "callbacks" => self.warn(value_location, Swagger2WarningKind::Callbacks),
+                _ => {
+                    result.insert(key.clone(), value.take());
+                }
+            }
+        }
+
+        if !parameters.is_empty() {
+            result.insert("parameters".to_owned(), Value::Array(parameters));
+        }
+        if let Some(responses) = result.shift_remove("responses") {
+            result.insert("responses".to_owned(), responses);
+        }
+
+        Value::Object(result)
+    }
+
+    fn convert_responses(
+        &mut self,
+        responses: &mut Value,
+        location: &str,
+        produces: &mut Vec<Value>,
+    ) -> Value {
+        let mut result = Map::new();
+
+        let responses = match responses.as_object_mut() {
+            Some(responses) => responses,
+            None => return Value::Object(result),
+        };
+
+        for (code, response) in responses.iter_mut() {
+            let response_location = format!("{}/{}", location, code);
+
+            if let Some(Value::Object(content)) = response.get("content") {
+                for media_type in content.keys() {
+                    let media_type = Value::from(media_type.clone());
+                    if !produces.contains(&media_type) {
+                        produces.push(media_type);
+                    }
+                }
+            }
+
+            let mut converted = Map::new();
+            converted.insert("description".to_owned(), response["description"].take());
+
+            if let Some(schema) = self.convert_content(response, &response_location) {
+                converted.insert("schema".to_owned(), schema);
+            }
+
+            if let Some(Value::Object(headers)) = response.get_mut("headers") {
+                let headers = headers
+                    .iter_mut()
+                    .map(|(name, header)| {
+                        let location =
+                            format!("{}/headers/{}", response_location, escape_pointer(name));
+                        let mut converted = Map::new();
+                        if let Some(description) = header.get_mut("description") {
+                            converted.insert("description".to_owned(), description.take());
+                        }
+                        self.inline_parameter_schema(name, header, &mut converted, &location);
+                        (name.clone(), Value::Object(converted))
+                    })
+                    .collect();
+                converted.insert("headers".to_owned(), Value::Object(headers));
+            }
+
+            if response.get("links").is_some() {
+                self.warn(
+                    format!("{}/links", response_location),
+                    Swagger2WarningKind::Links,
+                );
+            }
+
+            result.insert(code.clone(), Value::Object(converted));
+        }
+
+        Value::Object(result)
+    }
+
+    /// Take schema of the first media type
+    fn convert_content(&mut self, container: &mut Value, location: &str) -> Option<Value> {
+        let content = match container.get_mut("content") {
+            Some(Value::Object(content)) => content,
+            _ => return None,
+        };
+
+        for (name, media_type) in content.iter() {
+            let media_type_location = format!("{}/content/{}", location, escape_pointer(name));
+            if media_type.get("examples").is_some() {
+                self.warn(
+                    format!("{}/examples", media_type_location),
+                    Swagger2WarningKind::Examples,
+                );
+            }
+            if media_type.get("encoding").is_some() {
+                self.warn(
+                    format!("{}/encoding", media_type_location),
+                    Swagger2WarningKind::Encoding,
+                );
+            }
+        }
+
+        let mut media_types = content.iter_mut();
+        let (first_name, first) = media_types.next()?;
+        let first_location = format!("{}/content/{}/schema", location, escape_pointer(first_name));
+        let mut schema = first.get_mut("schema").map(Value::take)?;
+
+        if media_types.any(|(_, media_type)| media_type.get("schema") != Some(&schema)) {
+            self.warn(
+                format!("{}/content", location),
+                Swagger2WarningKind::MultipleMediaTypes,
+            );
+        }
+
+        self.convert_schema(&mut schema, &first_location);
+        Some(schema)
+    }
+
+    fn convert_parameters(&mut self, parameters: &mut Value, location: &str) -> Value {
+        let parameters = match parameters.as_array_mut() {
+            Some(parameters) => parameters,
+            None => return Value::Array(Vec::new()),
+        };
+
+        let mut result = Vec::new();
+
+        for (index, parameter) in parameters.iter_mut().enumerate() {
+            let parameter_location = format!("{}/{}", location, index);
+            let name = parameter["name"].as_str().unwrap_or_default().to_owned();
+
+            if parameter["in"] == "cookie" {
+                self.warn(
+                    parameter_location,
+                    Swagger2WarningKind::CookieParameter(name),
+                );
+                continue;
+            }
+
+            let mut converted = Map::new();
+            for key in &["name", "in", "description", "required"] {
+                if let Some(value) = parameter.get_mut(key) {
+                    converted.insert((*key).to_owned(), value.take());
+                }
+            }
+            self.inline_parameter_schema(&name, parameter, &mut converted, &parameter_location);
+
+            result.push(Value::Object(converted));
+        }
+
+        Value::Array(result)
+    }
+
+    /// Move schema keywords of a parameter or a header into the object itself
+    fn inline_parameter_schema(
+        &mut self,
+        name: &str,
+        parameter: &mut Value,
+        converted: &mut Map<String, Value>,
+        location: &str,
+    ) {
+        let schema_location = format!("{}/schema", location);
+        let mut schema = match parameter.get_mut("schema").map(Value::take) {
+            Some(schema) => schema,
+            None => {
+                converted.insert("type".to_owned(), "string".into());
+                return;
+            }
+        };
+        let referenced = schema["$ref"]
+            .as_str()
+            .and_then(|link| link.strip_prefix(crate::SCHEMA_REFERENCE_PREFIX))
+            .and_then(|name| self.schemas.get(name));
+        if let Some(referenced) = referenced {
+            schema = referenced.clone();
+        }
+        self.convert_schema(&mut schema, &schema_location);
+
+        let is_primitive = match schema.get("type").and_then(Value::as_str) {
+            Some("object") | None => false,
+            Some(_) => true,
+        };
+        if !is_primitive {
+            self.warn(
+                schema_location,
+                Swagger2WarningKind::ComplexParameter(name.to_owned()),
+            );
+            converted.insert("type".to_owned(), "string".into());
+            return;
+        }
+
+        if let Value::Object(schema) = schema {
+            for (key, value) in schema {
+                if key == "example" {
+                    continue;
+                }
+                if key != "description" || !converted.contains_key("description") {
+                    converted.insert(key, value);
+                }
+            }
+        }
+    }
+
+    /// Convert OpenAPI 3.0 schema into Swagger 2.0 schema in place
+    fn convert_schema(&mut self, schema: &mut Value, location: &str) {
+        let schema = match schema.as_object_mut() {
+            Some(schema) => schema,
+            None => return,
+        };
+
+        if let Some(Value::String(link)) = schema.get_mut("$ref") {
+            if let Some(name) = link.strip_prefix(crate::SCHEMA_REFERENCE_PREFIX) {
+                *link = format!("{}{}", DEFINITIONS_REFERENCE_PREFIX, name);
+            }
+        }
+
+        for keyword in &["oneOf", "anyOf"] {
+            if schema.shift_remove(*keyword).is_some() {
+                schema.shift_remove("discriminator");
+                self.warn(
+                    format!("{}/{}", location, keyword),
+                    Swagger2WarningKind::UnsupportedComposition(keyword),
+                );
+            }
+        }
+
+        if let Some(Value::Bool(nullable)) = schema.shift_remove("nullable") {
+            if nullable {
+                schema.insert("x-nullable".to_owned(), Value::Bool(true));
+            }
+        }
+        if let Some(Value::Bool(write_only)) = schema.shift_remove("writeOnly") {
+            if write_only {
+                schema.insert("x-writeOnly".to_owned(), Value::Bool(true));
+            }
+        }
+
+        if let Some(Value::Array(all_of)) = schema.get_mut("allOf") {
+            for (index, item) in all_of.iter_mut().enumerate() {
+                self.convert_schema(item, &format!("{}/allOf/{}", location, index));
+            }
+        }
+        if let Some(items) = schema.get_mut("items") {
+            self.convert_schema(items, &format!("{}/items", location));
+        }
+        if let Some(additional_properties) = schema.get_mut("additionalProperties") {
+            self.convert_schema(
+                additional_properties,
+                &format!("{}/additionalProperties", location),
+            );
+        }
+        if let Some(Value::Object(properties)) = schema.get_mut("properties") {
+            for (name, property) in properties.iter_mut() {
+                self.convert_schema(
+                    property,
+                    &format!("{}/properties/{}", location, escape_pointer(name)),
+                );
+            }
+        }
+    }
+
+    fn convert_security_scheme(
+        &mut self,
+        name: &str,
+        scheme: &mut Value,
+        location: &str,
+    ) -> Option<Value> {
+        let description = scheme.get_mut("description").map(Value::take);
+
+        let mut converted = match (scheme["type"].as_str(), scheme["scheme"].as_str()) {
+            (Some("http"), Some("basic")) => json!({ "type": "basic" }),
+            (Some("http"), Some("bearer")) => {
+                self.warn(
+                    location.to_owned(),
+                    Swagger2WarningKind::BearerAuth(name.to_owned()),
+                );
+                json!({ "type": "apiKey", "in": "header", "name": "Authorization" })
+            }
+            (Some("apiKey"), _) if scheme["in"] != "cookie" => json!({
+                "type": "apiKey",
+                "in": scheme["in"].take(),
+                "name": scheme["name"].take(),
+            }),
+            (Some("oauth2"), _) => {
+                let flows = scheme["flows"].as_object_mut()?;
+                if flows.len() > 1 {
+                    self.warn(
+                        format!("{}/flows", location),
+                        Swagger2WarningKind::MultipleOAuthFlows(name.to_owned()),
+                    );
+                }
+                let (flow_name, flow) = flows.iter_mut().next()?;
+
+                let mut converted = Map::new();
+                converted.insert("type".to_owned(), "oauth2".into());
+                let flow_name = match flow_name.as_str() {
+                    "clientCredentials" => "application",
+                    "authorizationCode" => "accessCode",
+                    flow_name => flow_name,
+                };
+                converted.insert("flow".to_owned(), flow_name.into());
+                for key in &["authorizationUrl", "tokenUrl"] {
+                    if let Some(url) = flow.get_mut(key) {
+                        converted.insert((*key).to_owned(), url.take());
+                    }
+                }
+                converted.insert(
+                    "scopes".to_owned(),
+                    flow.get_mut("scopes")
+                        .map(Value::take)
+                        .unwrap_or_else(|| Value::Object(Map::new())),
+                );
+                Value::Object(converted)
+            }
+            _ => {
+                self.warn(
+                    location.to_owned(),
+                    Swagger2WarningKind::UnsupportedSecurityScheme(name.to_owned()),
+                );
+                return None;
+            }
+        };
+
+        if let Some(description) = description {
+            converted["description"] = description;
+        }
+        Some(converted)
+    }
+}
+
+/// Split server url into `schemes`, `host` and `basePath`, substituting default variable values
+fn convert_server(server: &Value, document: &mut Map<String, Value>) {
+    let mut url = server["url"].as_str().unwrap_or_default().to_owned();
+    if let Some(Value::Object(variables)) = server.get("variables") {
+        for (name, variable) in variables {
+            let default = variable["default"].as_str().unwrap_or_default();
+            url = url.replace(&format!("{{{}}}", name), default);
+        }
+    }
+
+    let (scheme, rest) = match url.find("://") {
+        Some(index) => (Some(&url[..index]), &url[index + 3..]),
+        None => (None, url.as_str()),
+    };
+    let (host, base_path) = match rest.find('/') {
+        Some(index) => (&rest[..index], &rest[index..]),
+        None => (rest, ""),
+    };
+
+    if !host.is_empty() {
+        document.insert("host".to_owned(), host.into());
+    }
+    if !base_path.is_empty() {
+        document.insert("basePath".to_owned(), base_path.into());
+    }
+    if let Some(scheme) = scheme {
+        document.insert("schemes".to_owned(), vec![scheme].into());
+    }
+}
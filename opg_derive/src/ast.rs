@@ -9,7 +9,6 @@ pub struct Container<'a> {
     pub attrs: attr::Container,
     pub data: Data<'a>,
     pub generics: &'a syn::Generics,
-    pub original: &'a syn::DeriveInput,
 }
 
 pub enum Data<'a> {
@@ -28,7 +27,6 @@ pub struct Variant<'a> {
 pub struct Field<'a> {
     pub member: syn::Member,
     pub attrs: attr::Field,
-    pub ty: &'a syn::Type,
     pub original: &'a syn::Field,
 }
 
@@ -52,12 +50,17 @@ impl<'a> Container<'a> {
         match &mut data {
             Data::Enum(variants) => {
                 for variant in variants {
-                    variant.attrs.rename_by_rule(attrs.rename_rule);
+                    variant
+                        .attrs
+                        .rename_by_rule(attrs.rename_rule, attrs.de_rename_rule);
                     for field in &mut variant.fields {
                         if field.attrs.flatten {
                             has_flatten = true;
                         }
-                        field.attrs.rename_by_rule(variant.attrs.rename_rule);
+                        field.attrs.rename_by_rule(
+                            variant.attrs.rename_rule,
+                            variant.attrs.de_rename_rule,
+                        );
                     }
                 }
             }
@@ -66,7 +69,9 @@ impl<'a> Container<'a> {
                     if field.attrs.flatten {
                         has_flatten = true;
                     }
-                    field.attrs.rename_by_rule(attrs.rename_rule);
+                    field
+                        .attrs
+                        .rename_by_rule(attrs.rename_rule, attrs.de_rename_rule);
                 }
             }
         }
@@ -80,7 +85,6 @@ impl<'a> Container<'a> {
             attrs,
             data,
             generics: &input.generics,
-            original: input,
         };
         // TODO: check item
         Some(item)
@@ -163,7 +167,6 @@ fn fields_from_ast<'a>(
                 None => syn::Member::Unnamed(i.into()),
             },
             attrs: attr::Field::from_ast(cx, i, field),
-            ty: &field.ty,
             original: field,
         })
         .collect()
@@ -12,9 +12,9 @@ use crate::parsing_context::*;
 use crate::symbol::*;
 
 pub struct Container {
-    pub name: Name,
     pub rename_rule: RenameRule,
-    pub transparent: bool,
+    pub de_rename_rule: RenameRule,
+    pub default: SerdeDefault,
     pub tag_type: TagType,
     pub has_flatten: bool,
     pub has_repr: bool,
@@ -24,27 +24,35 @@ pub struct Container {
     pub example: Option<syn::Expr>,
     pub inline: bool,
     pub nullable: bool,
+    pub validation: Option<Validation>,
+    pub generic_separator: Option<String>,
     pub explicit_model_type: Option<ExplicitModelType>,
     pub model_type: ModelType,
+    pub schema_override: Option<SchemaOverride>,
 }
 
 impl Container {
     #[allow(clippy::cognitive_complexity)]
     pub fn from_ast(cx: &ParsingContext, input: &syn::DeriveInput) -> Self {
-        let mut ser_name = Attr::none(cx, RENAME);
         let mut rename_rule = Attr::none(cx, RENAME_ALL);
-        let mut transparent = BoolAttr::none(cx, TRANSPARENT);
+        let mut de_rename_rule = Attr::none(cx, RENAME_ALL);
+        let mut default = Attr::none(cx, DEFAULT);
         let mut untagged = BoolAttr::none(cx, UNTAGGED);
         let mut internal_tag = Attr::none(cx, TAG);
         let mut content = Attr::none(cx, CONTENT);
         let mut has_repr = BoolAttr::none(cx, REPR);
 
         let mut description = Attr::none(cx, DESCRIPTION);
+        let mut skip_doc = BoolAttr::none(cx, SKIP_DOC);
         let mut format = Attr::none(cx, FORMAT);
         let mut example = Attr::none(cx, EXAMPLE);
         let mut inline = BoolAttr::none(cx, INLINE);
         let mut nullable = BoolAttr::none(cx, NULLABLE);
+        let mut validation = ValidationAttr::none(cx);
+        let mut generic_separator = Attr::none(cx, GENERIC_SEPARATOR);
         let mut model_type = OneOfFlagsAttr::none(cx);
+        let mut schema_with = Attr::none(cx, SCHEMA_WITH);
+        let mut as_type = Attr::none(cx, AS);
 
         for (from, meta_item) in input
             .attrs
@@ -53,32 +61,36 @@ impl Container {
             .flat_map(|item| item.into_iter())
         {
             match (from, &meta_item) {
-                (AttrFrom::Serde, Meta(NameValue(m))) if m.path == RENAME => {
-                    if let Ok(s) = get_lit_str(cx, RENAME, &m.lit) {
-                        ser_name.set(&m.path, s.value());
-                    }
-                }
-                (AttrFrom::Serde, Meta(List(m))) if m.path == RENAME => {
-                    if let Ok(ser) = get_renames(cx, &m.nested) {
-                        ser_name.set_opt(&m.path, ser.map(syn::LitStr::value));
-                    }
-                }
                 (AttrFrom::Serde, Meta(NameValue(m))) if m.path == RENAME_ALL => {
                     if let Ok(rule) = get_lit_str(cx, RENAME_ALL, &m.lit)
                         .and_then(|s| RenameRule::from_str(&s.value()))
                     {
-                        rename_rule.set(&m.path, rule)
+                        rename_rule.set(&m.path, rule);
+                        de_rename_rule.set(&m.path, rule);
                     }
                 }
                 (AttrFrom::Serde, Meta(List(m))) if m.path == RENAME_ALL => {
-                    if let Ok(Some(rule)) = get_renames(cx, &m.nested) {
-                        if let Ok(rule) = RenameRule::from_str(&rule.value()) {
-                            rename_rule.set(&m.path, rule)
+                    if let Ok((ser, de)) = get_renames(cx, &m.nested) {
+                        if let Some(Ok(rule)) = ser.map(|rule| RenameRule::from_str(&rule.value()))
+                        {
+                            rename_rule.set(&m.path, rule);
                         }
+                        if let Some(Ok(rule)) = de.map(|rule| RenameRule::from_str(&rule.value())) {
+                            de_rename_rule.set(&m.path, rule);
+                        }
+                    }
+                }
+                (AttrFrom::Serde, Meta(Path(word))) if word == DEFAULT => {
+                    if let syn::Data::Struct(_) = input.data {
+                        default.set(word, SerdeDefault::Default);
                     }
                 }
-                (AttrFrom::Serde, Meta(Path(word))) if word == TRANSPARENT => {
-                    transparent.set_true(word);
+                (AttrFrom::Serde, Meta(NameValue(m))) if m.path == DEFAULT => {
+                    if let syn::Data::Struct(_) = input.data {
+                        if let Ok(path) = parse_lit_into_expr_path(cx, DEFAULT, &m.lit) {
+                            default.set(&m.path, SerdeDefault::Path(path));
+                        }
+                    }
                 }
                 (AttrFrom::Serde, Meta(Path(word))) if word == UNTAGGED => {
                     if let syn::Data::Enum(_) = input.data {
@@ -118,9 +130,7 @@ impl Container {
                     }
                 }
                 (AttrFrom::Opg, Meta(NameValue(m))) if m.path == EXAMPLE => {
-                    if let Ok(s) = get_lit_str(cx, EXAMPLE, &m.lit) {
-                        example.set(&m.path, lit_str_expr(s));
-                    }
+                    example.set(&m.path, lit_expr(&m.lit));
                 }
 
                 (AttrFrom::Opg, Meta(NameValue(m))) if m.path == EXAMPLE_WITH => {
@@ -128,6 +138,27 @@ impl Container {
                         example.set(&m.path, expr);
                     }
                 }
+
+                (AttrFrom::Opg, Meta(NameValue(m))) if m.path == SCHEMA_WITH => {
+                    if let Ok(path) = parse_lit_into_expr_path(cx, SCHEMA_WITH, &m.lit) {
+                        schema_with.set(&m.path, path);
+                    }
+                }
+
+                (AttrFrom::Opg, Meta(NameValue(m))) if m.path == AS => {
+                    if let Ok(ty) = parse_lit_into_ty(cx, AS, &m.lit) {
+                        as_type.set(&m.path, ty);
+                    }
+                }
+                (AttrFrom::Opg, Meta(NameValue(m))) if m.path == GENERIC_SEPARATOR => {
+                    if let Ok(s) = get_lit_str(cx, GENERIC_SEPARATOR, &m.lit) {
+                        generic_separator.set(&m.path, s.value());
+                    }
+                }
+                (AttrFrom::Opg, Meta(meta)) if ValidationAttr::is_keyword(meta.path()) => {
+                    validation.set(meta)
+                }
+                (AttrFrom::Opg, Meta(Path(word))) if word == SKIP_DOC => skip_doc.set_true(word),
                 (AttrFrom::Opg, Meta(Path(word))) if word == INLINE => inline.set_true(word),
                 (AttrFrom::Opg, Meta(Path(word))) if word == NULLABLE => nullable.set_true(word),
                 (AttrFrom::Opg, Meta(Path(word))) => {
@@ -159,19 +190,24 @@ impl Container {
         let model_type = decide_model_type(cx, input, &tag_type).unwrap_or(ModelType::Object);
 
         Self {
-            name: Name::from_attrs(unraw(&input.ident), ser_name),
             rename_rule: rename_rule.get().unwrap_or(RenameRule::None),
-            transparent: transparent.get(),
+            de_rename_rule: de_rename_rule.get().unwrap_or(RenameRule::None),
+            default: default.get().unwrap_or(SerdeDefault::None),
             tag_type,
             has_flatten: false,
             has_repr: has_repr.get(),
-            description: description.get(),
+            description: description
+                .get()
+                .or_else(|| get_doc_comment(&input.attrs).filter(|_| !skip_doc.get())),
             format: format.get(),
             example: example.get(),
             inline: inline.get(),
             nullable: nullable.get(),
+            validation: validation.get(),
+            generic_separator: generic_separator.get(),
             explicit_model_type,
             model_type,
+            schema_override: get_schema_override(cx, schema_with, as_type),
         }
     }
 }
@@ -179,26 +215,33 @@ impl Container {
 pub struct Variant {
     pub name: Name,
     pub rename_rule: RenameRule,
+    pub de_rename_rule: RenameRule,
     pub skip_serializing: bool,
 
     pub description: Option<String>,
     pub format: Option<String>,
     pub example: Option<syn::Expr>,
     pub inline: bool,
-    pub explicit_model_type: Option<ExplicitModelType>,
+    pub validation: Option<Validation>,
+    pub schema_override: Option<SchemaOverride>,
 }
 
 impl Variant {
     pub fn from_ast(cx: &ParsingContext, input: &syn::Variant) -> Self {
         let mut ser_name = Attr::none(cx, RENAME);
+        let mut de_name = Attr::none(cx, RENAME);
         let mut rename_rule = Attr::none(cx, RENAME_ALL);
+        let mut de_rename_rule = Attr::none(cx, RENAME_ALL);
         let mut skip_serializing = BoolAttr::none(cx, SKIP_SERIALIZING);
 
         let mut description = Attr::none(cx, DESCRIPTION);
+        let mut skip_doc = BoolAttr::none(cx, SKIP_DOC);
         let mut format = Attr::none(cx, FORMAT);
         let mut example = Attr::none(cx, EXAMPLE);
         let mut inline = BoolAttr::none(cx, INLINE);
-        let mut model_type = OneOfFlagsAttr::none(cx);
+        let mut validation = ValidationAttr::none(cx);
+        let mut schema_with = Attr::none(cx, SCHEMA_WITH);
+        let mut as_type = Attr::none(cx, AS);
 
         for (from, meta_item) in input
             .attrs
@@ -210,24 +253,31 @@ impl Variant {
                 (AttrFrom::Serde, Meta(NameValue(m))) if m.path == RENAME => {
                     if let Ok(s) = get_lit_str(cx, RENAME, &m.lit) {
                         ser_name.set(&m.path, s.value());
+                        de_name.set(&m.path, s.value());
                     }
                 }
                 (AttrFrom::Serde, Meta(List(m))) if m.path == RENAME => {
-                    if let Ok(ser) = get_renames(cx, &m.nested) {
+                    if let Ok((ser, de)) = get_renames(cx, &m.nested) {
                         ser_name.set_opt(&m.path, ser.map(syn::LitStr::value));
+                        de_name.set_opt(&m.path, de.map(syn::LitStr::value));
                     }
                 }
                 (AttrFrom::Serde, Meta(NameValue(m))) if m.path == RENAME_ALL => {
                     if let Ok(rule) = get_lit_str(cx, RENAME_ALL, &m.lit)
                         .and_then(|s| RenameRule::from_str(&s.value()))
                     {
-                        rename_rule.set(&m.path, rule)
+                        rename_rule.set(&m.path, rule);
+                        de_rename_rule.set(&m.path, rule);
                     }
                 }
                 (AttrFrom::Serde, Meta(List(m))) if m.path == RENAME_ALL => {
-                    if let Ok(Some(rule)) = get_renames(cx, &m.nested) {
-                        if let Ok(rule) = RenameRule::from_str(&rule.value()) {
-                            rename_rule.set(&m.path, rule)
+                    if let Ok((ser, de)) = get_renames(cx, &m.nested) {
+                        if let Some(Ok(rule)) = ser.map(|rule| RenameRule::from_str(&rule.value()))
+                        {
+                            rename_rule.set(&m.path, rule);
+                        }
+                        if let Some(Ok(rule)) = de.map(|rule| RenameRule::from_str(&rule.value())) {
+                            de_rename_rule.set(&m.path, rule);
                         }
                     }
                 }
@@ -246,9 +296,7 @@ impl Variant {
                     }
                 }
                 (AttrFrom::Opg, Meta(NameValue(m))) if m.path == EXAMPLE => {
-                    if let Ok(s) = get_lit_str(cx, EXAMPLE, &m.lit) {
-                        example.set(&m.path, lit_str_expr(s))
-                    }
+                    example.set(&m.path, lit_expr(&m.lit));
                 }
 
                 (AttrFrom::Opg, Meta(NameValue(m))) if m.path == EXAMPLE_WITH => {
@@ -256,11 +304,26 @@ impl Variant {
                         example.set(&m.path, expr);
                     }
                 }
+
+                (AttrFrom::Opg, Meta(NameValue(m))) if m.path == SCHEMA_WITH => {
+                    if let Ok(path) = parse_lit_into_expr_path(cx, SCHEMA_WITH, &m.lit) {
+                        schema_with.set(&m.path, path);
+                    }
+                }
+
+                (AttrFrom::Opg, Meta(NameValue(m))) if m.path == AS => {
+                    if let Ok(ty) = parse_lit_into_ty(cx, AS, &m.lit) {
+                        as_type.set(&m.path, ty);
+                    }
+                }
+                (AttrFrom::Opg, Meta(meta)) if ValidationAttr::is_keyword(meta.path()) => {
+                    validation.set(meta)
+                }
+                (AttrFrom::Opg, Meta(Path(word))) if word == SKIP_DOC => skip_doc.set_true(word),
                 (AttrFrom::Opg, Meta(Path(word))) if word == INLINE => inline.set_true(word),
                 (AttrFrom::Opg, Meta(Path(word))) => {
-                    if let Ok(t) = ExplicitModelType::from_path(word) {
-                        model_type.set(word, t);
-                    } else {
+                    // model type of a variant is described by its fields
+                    if ExplicitModelType::from_path(word).is_err() {
                         cx.error_spanned_by(word, "unknown attribute")
                     }
                 }
@@ -280,27 +343,34 @@ impl Variant {
         }
 
         Variant {
-            name: Name::from_attrs(unraw(&input.ident), ser_name),
+            name: Name::from_attrs(unraw(&input.ident), ser_name, de_name),
             rename_rule: rename_rule.get().unwrap_or(RenameRule::None),
+            de_rename_rule: de_rename_rule.get().unwrap_or(RenameRule::None),
             skip_serializing: skip_serializing.get(),
-            description: description.get(),
+            description: description
+                .get()
+                .or_else(|| get_doc_comment(&input.attrs).filter(|_| !skip_doc.get())),
             format: format.get(),
             example: example.get(),
             inline: inline.get(),
-            explicit_model_type: model_type.at_most_one(),
+            validation: validation.get(),
+            schema_override: get_schema_override(cx, schema_with, as_type),
         }
     }
 
-    pub fn rename_by_rule(&mut self, rule: RenameRule) {
-        self.name.rename_as_variant(rule);
+    pub fn rename_by_rule(&mut self, rule: RenameRule, de_rule: RenameRule) {
+        self.name.rename_as_variant(rule, de_rule);
     }
 }
 
 pub struct Field {
     pub name: Name,
+    pub aliases: Vec<String>,
     pub skip_serializing: bool,
+    pub skip_deserializing: bool,
+    pub default: SerdeDefault,
+    pub default_value: Option<syn::Expr>,
     pub flatten: bool,
-    pub transparent: bool,
 
     pub optional: bool,
     pub description: Option<String>,
@@ -308,23 +378,34 @@ pub struct Field {
     pub example: Option<syn::Expr>,
     pub inline: bool,
     pub nullable: bool,
+    pub validation: Option<Validation>,
     pub explicit_model_type: Option<ExplicitModelType>,
+    pub schema_override: Option<SchemaOverride>,
 }
 
 impl Field {
     pub fn from_ast(cx: &ParsingContext, index: usize, input: &syn::Field) -> Self {
         let mut ser_name = Attr::none(cx, RENAME);
+        let mut de_name = Attr::none(cx, RENAME);
+        let mut aliases = VecAttr::none(cx, ALIAS);
         let mut skip_serializing = BoolAttr::none(cx, SKIP_SERIALIZING);
+        let mut skip_deserializing = BoolAttr::none(cx, SKIP_DESERIALIZING);
+        let mut default = Attr::none(cx, DEFAULT);
         let mut skip_serializing_if = Attr::none(cx, SKIP_SERIALIZING_IF);
         let mut flatten = BoolAttr::none(cx, FLATTEN);
 
         let mut optional = BoolAttr::none(cx, OPTIONAL);
         let mut description = Attr::none(cx, DESCRIPTION);
+        let mut skip_doc = BoolAttr::none(cx, SKIP_DOC);
         let mut format = Attr::none(cx, FORMAT);
         let mut example = Attr::none(cx, EXAMPLE);
+        let mut default_value = Attr::none(cx, DEFAULT);
         let mut inline = BoolAttr::none(cx, INLINE);
         let mut nullable = BoolAttr::none(cx, NULLABLE);
+        let mut validation = ValidationAttr::none(cx);
         let mut model_type = OneOfFlagsAttr::none(cx);
+        let mut schema_with = Attr::none(cx, SCHEMA_WITH);
+        let mut as_type = Attr::none(cx, AS);
 
         let ident = match &input.ident {
             Some(ident) => unraw(ident),
@@ -341,16 +422,38 @@ impl Field {
                 (AttrFrom::Serde, Meta(NameValue(m))) if m.path == RENAME => {
                     if let Ok(s) = get_lit_str(cx, RENAME, &m.lit) {
                         ser_name.set(&m.path, s.value());
+                        de_name.set(&m.path, s.value());
                     }
                 }
                 (AttrFrom::Serde, Meta(List(m))) if m.path == RENAME => {
-                    if let Ok(ser) = get_renames(cx, &m.nested) {
+                    if let Ok((ser, de)) = get_renames(cx, &m.nested) {
                         ser_name.set_opt(&m.path, ser.map(syn::LitStr::value));
+                        de_name.set_opt(&m.path, de.map(syn::LitStr::value));
                     }
                 }
-                (AttrFrom::Serde, Meta(Path(word))) if word == SKIP || word == SKIP_SERIALIZING => {
+                (AttrFrom::Serde, Meta(NameValue(m))) if m.path == ALIAS => {
+                    if let Ok(s) = get_lit_str(cx, ALIAS, &m.lit) {
+                        aliases.insert(&m.path, s.value());
+                    }
+                }
+                (AttrFrom::Serde, Meta(Path(word))) if word == SKIP => {
+                    skip_serializing.set_true(word);
+                    skip_deserializing.set_true(word);
+                }
+                (AttrFrom::Serde, Meta(Path(word))) if word == SKIP_SERIALIZING => {
                     skip_serializing.set_true(word);
                 }
+                (AttrFrom::Serde, Meta(Path(word))) if word == SKIP_DESERIALIZING => {
+                    skip_deserializing.set_true(word);
+                }
+                (AttrFrom::Serde, Meta(Path(word))) if word == DEFAULT => {
+                    default.set(word, SerdeDefault::Default);
+                }
+                (AttrFrom::Serde, Meta(NameValue(m))) if m.path == DEFAULT => {
+                    if let Ok(path) = parse_lit_into_expr_path(cx, DEFAULT, &m.lit) {
+                        default.set(&m.path, SerdeDefault::Path(path));
+                    }
+                }
                 (AttrFrom::Serde, Meta(NameValue(m))) if m.path == SKIP_SERIALIZING_IF => {
                     if let Ok(path) = parse_lit_into_expr_path(cx, SKIP_SERIALIZING_IF, &m.lit) {
                         skip_serializing_if.set(&m.path, path);
@@ -371,16 +474,36 @@ impl Field {
                     }
                 }
                 (AttrFrom::Opg, Meta(NameValue(m))) if m.path == EXAMPLE => {
-                    if let Ok(s) = get_lit_str(cx, EXAMPLE, &m.lit) {
-                        example.set(&m.path, lit_str_expr(s))
-                    }
+                    example.set(&m.path, lit_expr(&m.lit));
                 }
                 (AttrFrom::Opg, Meta(NameValue(m))) if m.path == EXAMPLE_WITH => {
                     if let Ok(expr) = parse_lit_into_expr(cx, EXAMPLE_WITH, &m.lit) {
                         example.set(&m.path, expr);
                     }
                 }
+                (AttrFrom::Opg, Meta(NameValue(m))) if m.path == SCHEMA_WITH => {
+                    if let Ok(path) = parse_lit_into_expr_path(cx, SCHEMA_WITH, &m.lit) {
+                        schema_with.set(&m.path, path);
+                    }
+                }
+                (AttrFrom::Opg, Meta(NameValue(m))) if m.path == AS => {
+                    if let Ok(ty) = parse_lit_into_ty(cx, AS, &m.lit) {
+                        as_type.set(&m.path, ty);
+                    }
+                }
+                (AttrFrom::Opg, Meta(NameValue(m))) if m.path == DEFAULT => {
+                    default_value.set(&m.path, lit_expr(&m.lit));
+                }
+                (AttrFrom::Opg, Meta(NameValue(m))) if m.path == DEFAULT_WITH => {
+                    if let Ok(expr) = parse_lit_into_expr(cx, DEFAULT_WITH, &m.lit) {
+                        default_value.set(&m.path, expr);
+                    }
+                }
                 (AttrFrom::Opg, Meta(Path(word))) if word == OPTIONAL => optional.set_true(word),
+                (AttrFrom::Opg, Meta(meta)) if ValidationAttr::is_keyword(meta.path()) => {
+                    validation.set(meta)
+                }
+                (AttrFrom::Opg, Meta(Path(word))) if word == SKIP_DOC => skip_doc.set_true(word),
                 (AttrFrom::Opg, Meta(Path(word))) if word == INLINE => inline.set_true(word),
                 (AttrFrom::Opg, Meta(Path(word))) if word == NULLABLE => nullable.set_true(word),
                 (AttrFrom::Opg, Meta(Path(word))) => {
@@ -406,22 +529,78 @@ impl Field {
         }
 
         Self {
-            name: Name::from_attrs(ident, ser_name),
+            name: Name::from_attrs(ident, ser_name, de_name),
+            aliases: aliases.get(),
             skip_serializing: skip_serializing.get(),
+            skip_deserializing: skip_deserializing.get(),
+            default: default.get().unwrap_or(SerdeDefault::None),
+            default_value: default_value.get(),
             flatten: flatten.get(),
-            transparent: false,
             optional: skip_serializing_if.get().is_some() || optional.get(),
-            description: description.get(),
+            description: description
+                .get()
+                .or_else(|| get_doc_comment(&input.attrs).filter(|_| !skip_doc.get())),
             format: format.get(),
             example: example.get(),
             inline: inline.get(),
             nullable: nullable.get(),
+            validation: validation.get(),
             explicit_model_type: model_type.at_most_one(),
+            schema_override: get_schema_override(cx, schema_with, as_type),
         }
     }
 
-    pub fn rename_by_rule(&mut self, rule: RenameRule) {
-        self.name.rename_as_field(rule);
+    pub fn rename_by_rule(&mut self, rule: RenameRule, de_rule: RenameRule) {
+        self.name.rename_as_field(rule, de_rule);
+    }
+
+    /// Whether the field is neither serialized nor deserialized
+    pub fn skip(&self) -> bool {
+        self.skip_serializing && self.skip_deserializing
+    }
+
+    /// Whether serde fills this field when it is missing in the input
+    pub fn is_defaulted(&self, container_default: &SerdeDefault) -> bool {
+        !self.default.is_none() || !container_default.is_none()
+    }
+
+    /// Whether the field can be omitted in all directions
+    pub fn is_optional(&self) -> bool {
+        self.optional || self.default_value.is_some()
+    }
+
+    /// Whether serialized and deserialized representations of this field differ
+    /// in a way which can't be expressed with `readOnly`/`writeOnly`
+    pub fn is_directional(&self, container_default: &SerdeDefault) -> bool {
+        let serialized = !self.skip_serializing;
+        let deserialized = !self.skip_deserializing;
+
+        (deserialized && !self.aliases.is_empty())
+            || (serialized
+                && deserialized
+                && (self.name.serialized() != self.name.deserialized()
+                    || (!self.is_optional() && self.is_defaulted(container_default))))
+    }
+}
+
+/// Hand-written schema of a field, variant or container
+pub enum SchemaOverride {
+    /// Function `fn(&mut Components) -> ModelReference`
+    With(syn::ExprPath),
+    /// Type whose schema is used instead
+    As(syn::Type),
+}
+
+/// Source of the value which serde uses for missing fields
+pub enum SerdeDefault {
+    None,
+    Default,
+    Path(syn::ExprPath),
+}
+
+impl SerdeDefault {
+    pub fn is_none(&self) -> bool {
+        matches!(self, SerdeDefault::None)
     }
 }
 
@@ -453,8 +632,193 @@ impl ExplicitModelType {
     }
 }
 
+/// Numeric keyword value, integers stay integers in the generated schema
+#[derive(Copy, Clone)]
+pub enum Number {
+    Integer(i64),
+    Float(f64),
+}
+
+#[derive(Default)]
+pub struct Validation {
+    pub minimum: Option<Number>,
+    pub maximum: Option<Number>,
+    pub exclusive_minimum: bool,
+    pub exclusive_maximum: bool,
+    pub multiple_of: Option<Number>,
+    pub min_length: Option<usize>,
+    pub max_length: Option<usize>,
+    pub pattern: Option<String>,
+    pub min_items: Option<usize>,
+    pub max_items: Option<usize>,
+    pub unique_items: bool,
+    pub min_properties: Option<usize>,
+    pub max_properties: Option<usize>,
+}
+
+struct ValidationAttr<'c> {
+    cx: &'c ParsingContext,
+    minimum: Attr<'c, Number>,
+    maximum: Attr<'c, Number>,
+    exclusive_minimum: BoolAttr<'c>,
+    exclusive_maximum: BoolAttr<'c>,
+    multiple_of: Attr<'c, Number>,
+    min_length: Attr<'c, usize>,
+    max_length: Attr<'c, usize>,
+    pattern: Attr<'c, String>,
+    min_items: Attr<'c, usize>,
+    max_items: Attr<'c, usize>,
+    unique_items: BoolAttr<'c>,
+    min_properties: Attr<'c, usize>,
+    max_properties: Attr<'c, usize>,
+}
+
+impl<'c> ValidationAttr<'c> {
+    fn none(cx: &'c ParsingContext) -> Self {
+        Self {
+            cx,
+            minimum: Attr::none(cx, MINIMUM),
+            maximum: Attr::none(cx, MAXIMUM),
+            exclusive_minimum: BoolAttr::none(cx, EXCLUSIVE_MINIMUM),
+            exclusive_maximum: BoolAttr::none(cx, EXCLUSIVE_MAXIMUM),
+            multiple_of: Attr::none(cx, MULTIPLE_OF),
+            min_length: Attr::none(cx, MIN_LENGTH),
+            max_length: Attr::none(cx, MAX_LENGTH),
+            pattern: Attr::none(cx, PATTERN),
+            min_items: Attr::none(cx, MIN_ITEMS),
+            max_items: Attr::none(cx, MAX_ITEMS),
+            unique_items: BoolAttr::none(cx, UNIQUE_ITEMS),
+            min_properties: Attr::none(cx, MIN_PROPERTIES),
+            max_properties: Attr::none(cx, MAX_PROPERTIES),
+        }
+    }
+
+    fn is_keyword(path: &syn::Path) -> bool {
+        [
+            MINIMUM,
+            MAXIMUM,
+            EXCLUSIVE_MINIMUM,
+            EXCLUSIVE_MAXIMUM,
+            MULTIPLE_OF,
+            MIN_LENGTH,
+            MAX_LENGTH,
+            PATTERN,
+            MIN_ITEMS,
+            MAX_ITEMS,
+            UNIQUE_ITEMS,
+            MIN_PROPERTIES,
+            MAX_PROPERTIES,
+        ]
+        .iter()
+        .any(|symbol| path == *symbol)
+    }
+
+    fn set(&mut self, meta: &syn::Meta) {
+        let cx = self.cx;
+
+        match meta {
+            Path(word) if word == EXCLUSIVE_MINIMUM => self.exclusive_minimum.set_true(word),
+            Path(word) if word == EXCLUSIVE_MAXIMUM => self.exclusive_maximum.set_true(word),
+            Path(word) if word == UNIQUE_ITEMS => self.unique_items.set_true(word),
+            NameValue(m) if m.path == MINIMUM => {
+                if let Ok(value) = get_lit_number(cx, MINIMUM, &m.lit) {
+                    self.minimum.set(&m.path, value);
+                }
+            }
+            NameValue(m) if m.path == MAXIMUM => {
+                if let Ok(value) = get_lit_number(cx, MAXIMUM, &m.lit) {
+                    self.maximum.set(&m.path, value);
+                }
+            }
+            NameValue(m) if m.path == MULTIPLE_OF => {
+                if let Ok(value) = get_lit_number(cx, MULTIPLE_OF, &m.lit) {
+                    self.multiple_of.set(&m.path, value);
+                }
+            }
+            NameValue(m) if m.path == MIN_LENGTH => {
+                if let Ok(value) = get_lit_usize(cx, MIN_LENGTH, &m.lit) {
+                    self.min_length.set(&m.path, value);
+                }
+            }
+            NameValue(m) if m.path == MAX_LENGTH => {
+                if let Ok(value) = get_lit_usize(cx, MAX_LENGTH, &m.lit) {
+                    self.max_length.set(&m.path, value);
+                }
+            }
+            NameValue(m) if m.path == PATTERN => {
+                if let Ok(s) = get_lit_str(cx, PATTERN, &m.lit) {
+                    self.pattern.set(&m.path, s.value());
+                }
+            }
+            NameValue(m) if m.path == MIN_ITEMS => {
+                if let Ok(value) = get_lit_usize(cx, MIN_ITEMS, &m.lit) {
+                    self.min_items.set(&m.path, value);
+                }
+            }
+            NameValue(m) if m.path == MAX_ITEMS => {
+                if let Ok(value) = get_lit_usize(cx, MAX_ITEMS, &m.lit) {
+                    self.max_items.set(&m.path, value);
+                }
+            }
+            NameValue(m) if m.path == MIN_PROPERTIES => {
+                if let Ok(value) = get_lit_usize(cx, MIN_PROPERTIES, &m.lit) {
+                    self.min_properties.set(&m.path, value);
+                }
+            }
+            NameValue(m) if m.path == MAX_PROPERTIES => {
+                if let Ok(value) = get_lit_usize(cx, MAX_PROPERTIES, &m.lit) {
+                    self.max_properties.set(&m.path, value);
+                }
+            }
+            meta => {
+                let path = meta.path().into_token_stream().to_string().replace(' ', "");
+                cx.error_spanned_by(meta, format!("malformed opg attribute `{}`", path));
+            }
+        }
+    }
+
+    fn get(self) -> Option<Validation> {
+        let validation = Validation {
+            minimum: self.minimum.get(),
+            maximum: self.maximum.get(),
+            exclusive_minimum: self.exclusive_minimum.get(),
+            exclusive_maximum: self.exclusive_maximum.get(),
+            multiple_of: self.multiple_of.get(),
+            min_length: self.min_length.get(),
+            max_length: self.max_length.get(),
+            pattern: self.pattern.get(),
+            min_items: self.min_items.get(),
+            max_items: self.max_items.get(),
+            unique_items: self.unique_items.get(),
+            min_properties: self.min_properties.get(),
+            max_properties: self.max_properties.get(),
+        };
+
+        let is_empty = validation.minimum.is_none()
+            && validation.maximum.is_none()
+            && !validation.exclusive_minimum
+            && !validation.exclusive_maximum
+            && validation.multiple_of.is_none()
+            && validation.min_length.is_none()
+            && validation.max_length.is_none()
+            && validation.pattern.is_none()
+            && validation.min_items.is_none()
+            && validation.max_items.is_none()
+            && !validation.unique_items
+            && validation.min_properties.is_none()
+            && validation.max_properties.is_none();
+
+        if is_empty {
+            None
+        } else {
+            Some(validation)
+        }
+    }
+}
+
 #[derive(Copy, Clone)]
 pub enum ModelType {
+    Null,
     NewType,
     Object,
     OneOf,
@@ -495,13 +859,7 @@ fn decide_model_type(
                 .all(|field| matches!(field.fields, syn::Fields::Unit))
             {
                 return match tag_type {
-                    TagType::None => {
-                        cx.error_spanned_by(
-                            &input.ident,
-                            "unit enums are not supported for untagged",
-                        );
-                        Err(())
-                    }
+                    TagType::None => Ok(ModelType::Null),
                     _ => Ok(ModelType::NewType),
                 };
             }
@@ -515,10 +873,7 @@ fn decide_model_type(
         syn::Data::Struct(syn::DataStruct { fields, .. }) => match fields {
             syn::Fields::Named(_) => ModelType::Object,
             syn::Fields::Unnamed(_) => ModelType::NewType,
-            syn::Fields::Unit => {
-                cx.error_spanned_by(&input.ident, "unit structs are not supported");
-                return Err(());
-            }
+            syn::Fields::Unit => ModelType::Null,
         },
         _ => {
             cx.error_spanned_by(&input.ident, "unions are not supported");
@@ -527,19 +882,43 @@ fn decide_model_type(
     })
 }
 
+/// Combine `schema_with` and `as` attributes, which can't be used together
+fn get_schema_override(
+    cx: &ParsingContext,
+    schema_with: Attr<syn::ExprPath>,
+    as_type: Attr<syn::Type>,
+) -> Option<SchemaOverride> {
+    match (schema_with.get(), as_type.get_with_tokens()) {
+        (Some(_), Some((tokens, _))) => {
+            cx.error_spanned_by(
+                tokens,
+                format!("`{}` can't be used together with `{}`", AS, SCHEMA_WITH),
+            );
+            None
+        }
+        (Some(path), None) => Some(SchemaOverride::With(path)),
+        (None, Some((_, ty))) => Some(SchemaOverride::As(ty)),
+        (None, None) => None,
+    }
+}
+
 fn get_renames<'a>(
     cx: &ParsingContext,
     items: &'a Punctuated<syn::NestedMeta, syn::Token![,]>,
-) -> Result<Option<&'a syn::LitStr>, ()> {
-    get_ser(cx, RENAME, items)?.at_most_one()
+) -> Result<SerAndDe<&'a syn::LitStr>, ()> {
+    let (ser, de) = get_ser_and_de(cx, RENAME, items)?;
+    Ok((ser.at_most_one()?, de.at_most_one()?))
 }
 
-fn get_ser<'c, 'm>(
+type SerAndDe<T> = (Option<T>, Option<T>);
+
+fn get_ser_and_de<'c, 'm>(
     cx: &'c ParsingContext,
     attr_name: Symbol,
     metas: &'m Punctuated<syn::NestedMeta, syn::Token![,]>,
-) -> Result<VecAttr<'c, &'m syn::LitStr>, ()> {
+) -> Result<(VecAttr<'c, &'m syn::LitStr>, VecAttr<'c, &'m syn::LitStr>), ()> {
     let mut ser_meta = VecAttr::none(cx, attr_name);
+    let mut de_meta = VecAttr::none(cx, attr_name);
 
     for meta in metas {
         match meta {
@@ -548,18 +927,22 @@ fn get_ser<'c, 'm>(
                     ser_meta.insert(&m.path, value);
                 }
             }
-            Meta(NameValue(m)) if m.path == DESERIALIZE => {}
+            Meta(NameValue(m)) if m.path == DESERIALIZE => {
+                if let Ok(value) = get_lit_str_simple(&m.lit) {
+                    de_meta.insert(&m.path, value);
+                }
+            }
             _ => return Err(()),
         }
     }
 
-    Ok(ser_meta)
+    Ok((ser_meta, de_meta))
 }
 
-fn lit_str_expr(lit: &syn::LitStr) -> syn::Expr {
+fn lit_expr(lit: &syn::Lit) -> syn::Expr {
     syn::Expr::Lit(syn::ExprLit {
         attrs: Vec::new(),
-        lit: syn::Lit::Str(lit.clone()),
+        lit: lit.clone(),
     })
 }
 
@@ -588,6 +971,17 @@ fn parse_lit_into_expr_path(
     })
 }
 
+fn parse_lit_into_ty(
+    cx: &ParsingContext,
+    attr_name: Symbol,
+    lit: &syn::Lit,
+) -> Result<syn::Type, ()> {
+    let string = get_lit_str(cx, attr_name, lit)?;
+    parse_lit_str(string).map_err(|_| {
+        cx.error_spanned_by(lit, format!("failed to parse type: {:?}", string.value()))
+    })
+}
+
 fn parse_lit_str<T>(s: &syn::LitStr) -> syn::parse::Result<T>
 where
     T: syn::parse::Parse,
@@ -652,6 +1046,83 @@ fn get_lit_str_special<'a>(
     }
 }
 
+fn get_lit_number(cx: &ParsingContext, attr_name: Symbol, lit: &syn::Lit) -> Result<Number, ()> {
+    let value = match lit {
+        syn::Lit::Int(lit) => lit.base10_parse::<i64>().map(Number::Integer),
+        syn::Lit::Float(lit) => lit.base10_parse::<f64>().map(Number::Float),
+        syn::Lit::Str(lit) => {
+            let value = lit.value();
+            match value.parse::<i64>() {
+                Ok(value) => Ok(Number::Integer(value)),
+                Err(_) => value
+                    .parse::<f64>()
+                    .map(Number::Float)
+                    .map_err(|err| syn::Error::new(lit.span(), err)),
+            }
+        }
+        _ => {
+            cx.error_spanned_by(
+                lit,
+                format!(
+                    "expected {} attribute to be a number: `{} = ...`",
+                    attr_name, attr_name
+                ),
+            );
+            return Err(());
+        }
+    };
+    match value {
+        Ok(Number::Float(value)) if !value.is_finite() => {
+            cx.error_spanned_by(lit, format!("{} attribute must be finite", attr_name));
+            Err(())
+        }
+        value => value.map_err(|err| cx.syn_error(err)),
+    }
+}
+
+fn get_lit_usize(cx: &ParsingContext, attr_name: Symbol, lit: &syn::Lit) -> Result<usize, ()> {
+    match lit {
+        syn::Lit::Int(lit) => lit.base10_parse::<usize>().map_err(|err| cx.syn_error(err)),
+        _ => {
+            cx.error_spanned_by(
+                lit,
+                format!(
+                    "expected {} attribute to be an integer: `{} = ...`",
+                    attr_name, attr_name
+                ),
+            );
+            Err(())
+        }
+    }
+}
+
+fn get_doc_comment(attrs: &[syn::Attribute]) -> Option<String> {
+    let lines = attrs
+        .iter()
+        .filter(|attr| attr.path == DOC)
+        .filter_map(|attr| match attr.parse_meta() {
+            Ok(NameValue(syn::MetaNameValue {
+                lit: syn::Lit::Str(s),
+                ..
+            })) => Some(s.value()),
+            _ => None,
+        })
+        .flat_map(|doc| {
+            doc.split('\n')
+                .map(|line| line.strip_prefix(' ').unwrap_or(line).trim_end().to_owned())
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    let description = lines.join("\n");
+    let description = description.trim();
+    if description.is_empty() {
+        None
+    } else {
+        Some(description.to_owned())
+    }
+}
+
 fn get_meta_items(
     cx: &ParsingContext,
     attr: &syn::Attribute,
@@ -855,32 +1326,48 @@ impl<'c, T> VecAttr<'c, T> {
 pub struct Name {
     source_name: String,
     serialized_name: String,
-    renamed: bool,
+    deserialized_name: String,
+    ser_renamed: bool,
+    de_renamed: bool,
 }
 
 #[allow(dead_code)]
 impl Name {
-    fn from_attrs(source_name: String, serialized_name: Attr<String>) -> Self {
+    fn from_attrs(
+        source_name: String,
+        serialized_name: Attr<String>,
+        deserialized_name: Attr<String>,
+    ) -> Self {
         let serialized_name = serialized_name.get();
-        let renamed = serialized_name.is_some();
+        let deserialized_name = deserialized_name.get();
+        let ser_renamed = serialized_name.is_some();
+        let de_renamed = deserialized_name.is_some();
 
         Self {
             source_name: source_name.clone(),
             serialized_name: serialized_name.unwrap_or_else(|| source_name.clone()),
-            renamed,
+            deserialized_name: deserialized_name.unwrap_or_else(|| source_name.clone()),
+            ser_renamed,
+            de_renamed,
         }
     }
 
-    pub fn rename_as_variant(&mut self, rename_rule: RenameRule) {
-        if !self.renamed {
+    pub fn rename_as_variant(&mut self, rename_rule: RenameRule, de_rename_rule: RenameRule) {
+        if !self.ser_renamed {
             self.serialized_name = rename_rule.apply_to_variant(&self.source_name);
         }
+        if !self.de_renamed {
+            self.deserialized_name = de_rename_rule.apply_to_variant(&self.source_name);
+        }
     }
 
-    pub fn rename_as_field(&mut self, rename_rule: RenameRule) {
-        if !self.renamed {
+    pub fn rename_as_field(&mut self, rename_rule: RenameRule, de_rename_rule: RenameRule) {
+        if !self.ser_renamed {
             self.serialized_name = rename_rule.apply_to_field(&self.source_name);
         }
+        if !self.de_renamed {
+            self.deserialized_name = de_rename_rule.apply_to_field(&self.source_name);
+        }
     }
 
     pub fn raw(&self) -> String {
@@ -890,4 +1377,8 @@ impl Name {
     pub fn serialized(&self) -> String {
         self.serialized_name.clone()
     }
+
+    pub fn deserialized(&self) -> String {
+        self.deserialized_name.clone()
+    }
 }
@@ -31,23 +31,35 @@ pub fn impl_derive_opg_model(
 
     // println!("{}", result.to_string());
 
-    Ok(dummy::wrap_in_const("OPG_MODEL", ident, result))
+    Ok(dummy::wrap_in_const(result))
 }
 
 fn build_generics(cont: &Container) -> syn::Generics {
     let generics = bound::without_default(cont.generics);
 
+    if cont.attrs.schema_override.is_some() {
+        return generics;
+    }
+
     bound::with_bound(
         cont,
         &generics,
         |field, variant| {
-            !field.skip_serializing && variant.map_or(true, |variant| !variant.skip_serializing)
+            !field.skip()
+                && field.schema_override.is_none()
+                && variant.is_none_or(|variant| {
+                    !variant.skip_serializing && variant.schema_override.is_none()
+                })
         },
         &syn::parse_quote!(_opg::OpgModel),
     )
 }
 
 fn serialize_body(container: &Container) -> proc_macro2::TokenStream {
+    if let Some(schema_override) = &container.attrs.schema_override {
+        return serialize_schema_override(container, schema_override);
+    }
+
     match &container.data {
         Data::Enum(variants) => serialize_enum(container, variants),
         Data::Struct(StructStyle::Struct, fields) => serialize_struct(container, fields),
@@ -55,12 +67,13 @@ fn serialize_body(container: &Container) -> proc_macro2::TokenStream {
         Data::Struct(StructStyle::NewType, fields) => {
             serialize_newtype_struct(container, &fields[0])
         }
-        _ => unimplemented!(),
+        Data::Struct(StructStyle::Unit, _) => serialize_null(container),
     }
 }
 
 fn serialize_enum(container: &Container, variants: &[Variant]) -> proc_macro2::TokenStream {
     match (container.attrs.model_type, &container.attrs.tag_type) {
+        (ModelType::Null, _) => serialize_null(container),
         (ModelType::NewType, _) => serialize_newtype_enum(container, variants),
         (ModelType::Object, TagType::Adjacent { tag, content }) => {
             serialize_adjacent_tagged_enum(container, variants, tag, content)
@@ -90,16 +103,18 @@ fn serialize_newtype_enum(container: &Container, variants: &[Variant]) -> proc_m
             })
             .map(|(name, discriminant)| {
                 let description = format!("{} variant", name);
-                let example = quote::ToTokens::to_token_stream(discriminant).to_string();
-
                 quote! {
                     _opg::Model {
                         description: Some(#description.to_owned()),
+                        example: _opg::example_value(&(#discriminant)),
+                        default: None,
+                        read_only: false,
+                        write_only: false,
                         data: _opg::ModelData::Single(_opg::ModelType {
                             nullable: false,
                             type_description: _opg::ModelTypeDescription::Integer(_opg::ModelSimple {
                                 format: None,
-                                example: Some(#example.to_owned()),
+                                ..Default::default()
                             })
                         }),
                     }
@@ -111,8 +126,14 @@ fn serialize_newtype_enum(container: &Container, variants: &[Variant]) -> proc_m
         quote! {
             _opg::Model {
                 description: #description,
+                example: None,
+                default: None,
+                read_only: false,
+                write_only: false,
                 data: _opg::ModelData::OneOf(_opg::ModelOneOf {
                     one_of: vec![#(#variants),*],
+                    discriminator: None,
+                    nullable: false,
                 })
             }
         }
@@ -123,26 +144,31 @@ fn serialize_newtype_enum(container: &Container, variants: &[Variant]) -> proc_m
             .map(|variant| variant.attrs.name.serialized())
             .collect::<Vec<_>>();
 
-        let example = option_string(variants.first().map(|x| x.as_str()));
+        let example = option_example_str(variants.first().map(|x| x.as_str()));
 
         quote! {
             _opg::Model {
                 description: #description,
+                example: #example,
+                default: None,
+                read_only: false,
+                write_only: false,
                 data: _opg::ModelData::Single(_opg::ModelType {
                     nullable: false,
                     type_description: _opg::ModelTypeDescription::String(_opg::ModelString {
                         variants: Some(vec![#(#variants.to_owned()),*]),
                         data: _opg::ModelSimple {
                             format: None,
-                            example: #example,
-                        }
+                            ..Default::default()
+                        },
+                        ..Default::default()
                     })
                 })
             }
         }
     };
 
-    implement_type(&container.ident, body, container.attrs.inline)
+    implement_type(container, body)
 }
 
 fn serialize_untagged_enum(
@@ -151,22 +177,39 @@ fn serialize_untagged_enum(
 ) -> proc_macro2::TokenStream {
     let description = option_string(container.attrs.description.as_deref());
 
+    // All unit variants are serialized as `null`, so they share the same branch
+    let mut has_null = false;
+
     let one_of = variants
         .iter()
         .filter(|variant| !variant.attrs.skip_serializing)
-        .map(|variant| match &variant.style {
+        .filter_map(|variant| match &variant.style {
+            _ if variant.attrs.schema_override.is_some() => schema_override_reference(
+                variant.attrs.schema_override.as_ref(),
+                ContextParams::from(&variant.attrs),
+                variant.attrs.inline,
+            ),
             StructStyle::NewType => {
                 let field = &variant.fields[0];
                 let context_params = ContextParams::from(&field.attrs).or(&variant.attrs);
 
-                field_model_reference(context_params, field, variant.attrs.inline)
+                Some(field_model_reference(
+                    context_params,
+                    field,
+                    variant.attrs.inline,
+                ))
             }
-            StructStyle::Struct => inline_reference(object_model(
+            StructStyle::Struct => Some(inline_reference(object_model(
                 false,
                 &variant.attrs.description,
                 &variant.fields,
                 |field| variant.attrs.inline || field.attrs.inline,
-            )),
+            ))),
+            StructStyle::Unit if !has_null => {
+                has_null = true;
+                Some(inline_reference(null_model(&None)))
+            }
+            StructStyle::Unit => None,
             _ => unreachable!(),
         })
         .collect::<Vec<_>>();
@@ -174,13 +217,19 @@ fn serialize_untagged_enum(
     let body = quote! {
         _opg::Model {
             description: #description,
+            example: None,
+            default: None,
+            read_only: false,
+            write_only: false,
             data: _opg::ModelData::OneOf(_opg::ModelOneOf {
                 one_of: vec![#(#one_of),*],
+                discriminator: None,
+                nullable: false,
             })
         }
     };
 
-    implement_type(&container.ident, body, container.attrs.inline)
+    implement_type(container, body)
 }
 
 fn serialize_adjacent_tagged_enum(
@@ -192,98 +241,109 @@ fn serialize_adjacent_tagged_enum(
     let description = option_string(container.attrs.description.as_deref());
     let nullable = container.attrs.nullable;
 
-    let (variants, one_of) = variants
+    let type_name_stringified = container.ident.to_string();
+
+    let one_of = variants
         .iter()
         .filter(|variant| !variant.attrs.skip_serializing)
-        .fold(
-            (Vec::new(), Vec::new()),
-            |(mut variants, mut one_of), variant| {
-                let variant_name = variant.attrs.name.serialized();
-
-                let type_description = match &variant.style {
-                    StructStyle::NewType => {
-                        let field = &variant.fields[0];
-                        let context_params = ContextParams::from(&field.attrs).or(&variant.attrs);
-
-                        field_model_reference(context_params, field, variant.attrs.inline)
-                    }
-                    StructStyle::Tuple => inline_reference(tuple_model(
-                        false,
-                        &variant.attrs.description,
-                        &variant.fields,
-                        |field| variant.attrs.inline || field.attrs.inline,
-                    )),
-                    StructStyle::Struct => inline_reference(tuple_model(
-                        false,
-                        &variant.attrs.description,
-                        &variant.fields,
-                        |field| field.attrs.inline || variant.attrs.inline,
-                    )),
-                    _ => unreachable!(),
-                };
+        .map(|variant| {
+            let variant_name = variant.attrs.name.serialized();
+            let variant_ident = variant.ident.to_string();
+            let variant_description = option_string(variant.attrs.description.as_deref());
+
+            let content_model = match &variant.style {
+                _ if variant.attrs.schema_override.is_some() => schema_override_reference(
+                    variant.attrs.schema_override.as_ref(),
+                    ContextParams::new(),
+                    variant.attrs.inline,
+                ),
+                StructStyle::Unit => None,
+                StructStyle::NewType => {
+                    let field = &variant.fields[0];
+                    let context_params = ContextParams::from(&field.attrs).or(&variant.attrs);
 
-                variants.push(variant_name);
-                one_of.push(type_description);
-                (variants, one_of)
-            },
-        );
+                    Some(field_model_reference(context_params, field, variant.attrs.inline))
+                }
+                StructStyle::Tuple => Some(inline_reference(tuple_model(
+                    false,
+                    &None,
+                    &variant.fields,
+                    |field| variant.attrs.inline || field.attrs.inline,
+                ))),
+                StructStyle::Struct => Some(inline_reference(object_model(
+                    false,
+                    &None,
+                    &variant.fields,
+                    |field| variant.attrs.inline || field.attrs.inline,
+                ))),
+            };
 
-    let type_example = option_string(variants.first().map(|x| x.as_str()));
-    let type_name_stringified = container.ident.to_string();
+            let insert_content = match content_model {
+                Some(content_model) => quote! {
+                    properties.insert(#content.to_owned(), #content_model);
+                    required.push(#content.to_owned());
+                },
+                None => quote! {},
+            };
 
-    let struct_type_description = quote! {
-        {
-            let mut properties = std::collections::BTreeMap::new();
-            let mut required = Vec::new();
+            quote! {
+                cx.mention_variant_schema(
+                    type_name.as_deref(),
+                    #variant_ident,
+                    #variant_name,
+                    &mut discriminator,
+                    |cx| {
+                        let mut properties = std::collections::BTreeMap::new();
+                        let mut required = Vec::new();
 
-            properties.insert(#tag.to_owned(), _opg::ModelReference::Inline(
-                _opg::Model {
-                    description: Some(format!("{} type variant", #type_name_stringified)),
-                    data: _opg::ModelData::Single(_opg::ModelType {
-                        nullable: false,
-                        type_description: _opg::ModelTypeDescription::String(_opg::ModelString {
-                            variants: Some(vec![#(#variants.to_owned()),*]),
-                            data: _opg::ModelSimple {
-                                format: None,
-                                example: #type_example,
+                        properties.insert(#tag.to_owned(), _opg::ModelReference::Inline(
+                            _opg::Model {
+                                description: Some(format!("{} type variant", #type_name_stringified)),
+                                example: Some(#variant_name.into()),
+                                default: None,
+                                read_only: false,
+                                write_only: false,
+                                data: _opg::ModelData::Single(_opg::ModelType {
+                                    nullable: false,
+                                    type_description: _opg::ModelTypeDescription::String(_opg::ModelString {
+                                        variants: Some(vec![#variant_name.to_owned()]),
+                                        data: _opg::ModelSimple {
+                                            format: None,
+                                            ..Default::default()
+                                        },
+                                        ..Default::default()
+                                    })
+                                })
                             }
-                        })
-                    })
-                }
-            ));
-            required.push(#tag.to_owned());
-
-            properties.insert(#content.to_owned(), _opg::ModelReference::Inline(
-                _opg::Model {
-                    description: #description,
-                    data: _opg::ModelData::OneOf(_opg::ModelOneOf {
-                        one_of: vec![#(#one_of),*],
-                    })
-                }
-            ));
-            required.push(#content.to_owned());
-
-            _opg::ModelTypeDescription::Object(
-                _opg::ModelObject {
-                    properties,
-                    required,
-                    ..Default::default()
-                }
-            )
-        }
-    };
+                        ));
+                        required.push(#tag.to_owned());
+
+                        #insert_content
+
+                        _opg::Model {
+                            description: #variant_description,
+                            example: None,
+                            default: None,
+                            read_only: false,
+                            write_only: false,
+                            data: _opg::ModelData::Single(_opg::ModelType {
+                                nullable: #nullable,
+                                type_description: _opg::ModelTypeDescription::Object(_opg::ModelObject {
+                                    properties,
+                                    required,
+                                    ..Default::default()
+                                })
+                            })
+                        }
+                    },
+                )
+            }
+        })
+        .collect::<Vec<_>>();
 
-    let body = quote! {
-        _opg::Model {
-            description: #description,
-            data: _opg::ModelData::Single(_opg::ModelType {
-                nullable: #nullable,
-                type_description: #struct_type_description
-            })
-        }
-    };
+    let body = tagged_one_of_model(&description, tag, &one_of);
 
-    implement_type(&container.ident, body, container.attrs.inline)
+    implement_type(container, body)
 }
 
 fn serialize_external_tagged_enum(
@@ -302,6 +362,12 @@ fn serialize_external_tagged_enum(
                 let variant_name = variant.attrs.name.serialized();
 
                 let type_description = match &variant.style {
+                    _ if variant.attrs.schema_override.is_some() => schema_override_reference(
+                        variant.attrs.schema_override.as_ref(),
+                        ContextParams::from(&variant.attrs),
+                        variant.attrs.inline,
+                    )
+                    .unwrap_or_default(),
                     StructStyle::Unit => {
                         let description = option_string(variant.attrs.description.as_deref());
 
@@ -309,14 +375,19 @@ fn serialize_external_tagged_enum(
                             _opg::ModelReference::Inline(
                                 _opg::Model {
                                     description: #description,
+                                    example: Some(#variant_name.into()),
+                                    default: None,
+                                    read_only: false,
+                                    write_only: false,
                                     data: _opg::ModelData::Single(_opg::ModelType {
                                         nullable: false,
                                         type_description: _opg::ModelTypeDescription::String(_opg::ModelString {
                                             variants: Some(vec![#variant_name.to_owned()]),
                                             data: _opg::ModelSimple {
                                                 format: None,
-                                                example: Some(#variant_name.to_owned()),
-                                            }
+                                                ..Default::default()
+                                            },
+                                            ..Default::default()
                                         })
                                     })
                                 }
@@ -357,6 +428,10 @@ fn serialize_external_tagged_enum(
     let body = quote! {
         _opg::Model {
             description: #description,
+            example: None,
+            default: None,
+            read_only: false,
+            write_only: false,
             data: _opg::ModelData::Single(_opg::ModelType {
                 nullable: #nullable,
                 type_description: _opg::ModelTypeDescription::Object(
@@ -364,8 +439,14 @@ fn serialize_external_tagged_enum(
                         additional_properties: Some(Box::new(_opg::ModelReference::Inline(
                             _opg::Model {
                                 description: #description,
+                                example: None,
+                                default: None,
+                                read_only: false,
+                                write_only: false,
                                 data: _opg::ModelData::OneOf(_opg::ModelOneOf {
                                     one_of: vec![#(#one_of),*],
+                                    discriminator: None,
+                                    nullable: false,
                                 })
                             }
                         ))),
@@ -376,7 +457,7 @@ fn serialize_external_tagged_enum(
         }
     };
 
-    implement_type(&container.ident, body, container.attrs.inline)
+    implement_type(container, body)
 }
 
 fn serialize_internal_tagged_enum(
@@ -394,101 +475,219 @@ fn serialize_internal_tagged_enum(
         .filter(|variant| !variant.attrs.skip_serializing)
         .map(|variant| {
             let variant_name = variant.attrs.name.serialized();
+            let variant_ident = variant.ident.to_string();
 
-            let model = match &variant.style {
-                StructStyle::Unit => {
-                    object_model(false, &variant.attrs.description, &[], |_| false)
-                }
+            let variant_override = schema_override_reference(
+                variant.attrs.schema_override.as_ref(),
+                ContextParams::new(),
+                variant.attrs.inline,
+            )
+            .or_else(|| match &variant.style {
                 StructStyle::NewType => {
                     let field = &variant.fields[0];
-                    let type_name = &field.original.ty;
-                    let context_params = ContextParams::from(&field.attrs).or(&variant.attrs).tokenize();
+                    schema_override_reference(
+                        field.attrs.schema_override.as_ref(),
+                        ContextParams::from(&field.attrs),
+                        variant.attrs.inline,
+                    )
+                }
+                _ => None,
+            });
+
+            let merged_model = match variant_override {
+                // Hand-written schemas can't be merged, so the tag is added with `allOf`
+                Some(variant_override) => {
+                    let variant_description =
+                        option_string(variant.attrs.description.as_deref());
 
                     quote! {
-                        <#type_name as _opg::OpgModel>::get_schema_with_params(cx, &#context_params)
+                        _opg::Model {
+                            description: #variant_description,
+                            example: None,
+                            default: None,
+                            read_only: false,
+                            write_only: false,
+                            data: _opg::ModelType {
+                                nullable: #nullable,
+                                type_description: additional_object
+                            }.flatten(vec![#variant_override])
+                        }
                     }
                 }
-                StructStyle::Struct => {
-                    object_model(false, &variant.attrs.description, &variant.fields, |field| {
-                        variant.attrs.inline || field.attrs.inline
-                    })
+                None => {
+                    let model = internal_tagged_variant_model(variant);
+
+                    quote! {
+                        {
+                            let mut model = #model;
+                            let _ = model.try_merge(_opg::Model {
+                                description: None,
+                                example: None,
+                                default: None,
+                                read_only: false,
+                                write_only: false,
+                                data: _opg::ModelData::Single(_opg::ModelType {
+                                    nullable: #nullable,
+                                    type_description: additional_object
+                                })
+                            });
+                            model
+                        }
+                    }
                 }
-                _ => unreachable!(),
             };
 
             quote! {
-                {
-                    let mut model = #model;
-
-                    let additional_object = {
-                        let mut properties = std::collections::BTreeMap::new();
-
-                        properties.insert(#tag.to_owned(), _opg::ModelReference::Inline(
-                            _opg::Model {
-                                description: Some(format!("{} type variant", #type_name_stringified)),
-                                data: _opg::ModelData::Single(_opg::ModelType {
-                                    nullable: false,
-                                    type_description: _opg::ModelTypeDescription::String(_opg::ModelString {
-                                        variants: Some(vec![#variant_name.to_owned()]),
-                                        data: _opg::ModelSimple {
-                                            format: None,
-                                            example: Some(#variant_name.to_owned()),
-                                        }
+                cx.mention_variant_schema(
+                    type_name.as_deref(),
+                    #variant_ident,
+                    #variant_name,
+                    &mut discriminator,
+                    |cx| {
+                        let additional_object = {
+                            let mut properties = std::collections::BTreeMap::new();
+
+                            properties.insert(#tag.to_owned(), _opg::ModelReference::Inline(
+                                _opg::Model {
+                                    description: Some(format!("{} type variant", #type_name_stringified)),
+                                    example: Some(#variant_name.into()),
+                                    default: None,
+                                    read_only: false,
+                                    write_only: false,
+                                    data: _opg::ModelData::Single(_opg::ModelType {
+                                        nullable: false,
+                                        type_description: _opg::ModelTypeDescription::String(_opg::ModelString {
+                                            variants: Some(vec![#variant_name.to_owned()]),
+                                            data: _opg::ModelSimple {
+                                                format: None,
+                                                ..Default::default()
+                                            },
+                                            ..Default::default()
+                                        })
                                     })
-                                })
-                            }
-                        ));
-
-                        _opg::ModelTypeDescription::Object(_opg::ModelObject {
-                            properties,
-                            required: vec![#tag.to_owned()],
-                            ..Default::default()
-                        })
-                    };
+                                }
+                            ));
 
-                    let _ = model.try_merge(_opg::Model {
-                        description: None,
-                        data: _opg::ModelData::Single(_opg::ModelType {
-                            nullable: #nullable,
-                            type_description: additional_object
-                        })
-                    });
+                            _opg::ModelTypeDescription::Object(_opg::ModelObject {
+                                properties,
+                                required: vec![#tag.to_owned()],
+                                ..Default::default()
+                            })
+                        };
 
-                    _opg::ModelReference::Inline(model)
-                }
+                        #merged_model
+                    },
+                )
             }
         })
         .collect::<Vec<_>>();
 
-    let body = quote! {
-        _opg::Model {
-            description: #description,
-            data: _opg::ModelData::OneOf(_opg::ModelOneOf {
-                one_of: vec![#(#one_of),*],
-            })
+    let body = tagged_one_of_model(&description, tag, &one_of);
+
+    implement_type(container, body)
+}
+
+/// Variant schema of the internally tagged enum without the tag property
+fn internal_tagged_variant_model(variant: &Variant) -> proc_macro2::TokenStream {
+    match &variant.style {
+        StructStyle::Unit => object_model(false, &variant.attrs.description, &[], |_| false),
+        StructStyle::NewType => {
+            let field = &variant.fields[0];
+            let type_name = &field.original.ty;
+            let context_params = ContextParams::from(&field.attrs)
+                .or(&variant.attrs)
+                .tokenize();
+
+            quote! {
+                <#type_name as _opg::OpgModel>::get_schema_with_params(cx, &#context_params)
+            }
+        }
+        StructStyle::Struct => object_model(
+            false,
+            &variant.attrs.description,
+            &variant.fields,
+            |field| variant.attrs.inline || field.attrs.inline,
+        ),
+        _ => unreachable!(),
+    }
+}
+
+/// `oneOf` of tagged enum variants with discriminator
+///
+/// Each variant expression registers itself in `discriminator` mapping
+fn tagged_one_of_model(
+    description: &proc_macro2::TokenStream,
+    tag: &str,
+    one_of: &[proc_macro2::TokenStream],
+) -> proc_macro2::TokenStream {
+    quote! {
+        {
+            let type_name = <Self as _opg::OpgModel>::type_name();
+            let mut discriminator = _opg::ModelDiscriminator::new(#tag);
+
+            let one_of = vec![#(#one_of),*];
+
+            _opg::Model {
+                description: #description,
+                example: None,
+                default: None,
+                read_only: false,
+                write_only: false,
+                data: _opg::ModelData::OneOf(_opg::ModelOneOf {
+                    one_of,
+                    discriminator: Some(discriminator),
+                    nullable: false,
+                })
+            }
         }
+    }
+}
+
+fn serialize_schema_override(
+    container: &Container,
+    schema_override: &attr::SchemaOverride,
+) -> proc_macro2::TokenStream {
+    let context_params = ContextParams::from(&container.attrs).tokenize();
+
+    let body = match schema_override {
+        attr::SchemaOverride::With(path) => quote! {
+            #path(cx).into_model().apply_params(&#context_params)
+        },
+        attr::SchemaOverride::As(type_name) => quote! {
+            <#type_name as _opg::OpgModel>::get_schema_with_params(cx, &#context_params)
+        },
     };
 
-    implement_type(&container.ident, body, container.attrs.inline)
+    implement_type(container, body)
+}
+
+fn serialize_null(container: &Container) -> proc_macro2::TokenStream {
+    let body = null_model(&container.attrs.description);
+
+    implement_type(container, body)
 }
 
 fn serialize_struct(container: &Container, fields: &[Field]) -> proc_macro2::TokenStream {
     let description = option_string(container.attrs.description.as_deref());
     let nullable = container.attrs.nullable;
 
-    let object_type_description = object_type_description(fields, |field| field.attrs.inline);
+    let object_model_data =
+        object_model_data(nullable, fields, &container.attrs.default, |field| {
+            field.attrs.inline
+        });
 
     let body = quote! {
         _opg::Model {
             description: #description,
-            data: _opg::ModelData::Single(_opg::ModelType {
-                nullable: #nullable,
-                type_description: #object_type_description
-            })
+            example: None,
+            default: None,
+            read_only: false,
+            write_only: false,
+            data: #object_model_data
         }
     };
 
-    implement_type(&container.ident, body, container.attrs.inline)
+    implement_type(container, body)
 }
 
 fn serialize_tuple_struct(container: &Container, fields: &[Field]) -> proc_macro2::TokenStream {
@@ -500,6 +699,10 @@ fn serialize_tuple_struct(container: &Container, fields: &[Field]) -> proc_macro
     let body = quote! {
         _opg::Model {
             description: #description,
+            example: None,
+            default: None,
+            read_only: false,
+            write_only: false,
             data: _opg::ModelData::Single(_opg::ModelType {
                 nullable: #nullable,
                 type_description: #tuple_type_description
@@ -507,7 +710,7 @@ fn serialize_tuple_struct(container: &Container, fields: &[Field]) -> proc_macro
         }
     };
 
-    implement_type(&container.ident, body, container.attrs.inline)
+    implement_type(container, body)
 }
 
 fn serialize_newtype_struct(container: &Container, field: &Field) -> proc_macro2::TokenStream {
@@ -533,7 +736,7 @@ fn serialize_newtype_struct(container: &Container, field: &Field) -> proc_macro2
         }
     };
 
-    implement_type(&container.ident, body, container.attrs.inline)
+    implement_type(container, body)
 }
 
 fn tuple_model<P>(
@@ -551,6 +754,10 @@ where
     quote! {
         _opg::Model {
             description: #description,
+            example: None,
+            default: None,
+            read_only: false,
+            write_only: false,
             data: _opg::ModelData::Single(_opg::ModelType {
                 nullable: #nullable,
                 type_description: #tuple_type_description
@@ -559,6 +766,21 @@ where
     }
 }
 
+fn null_model(description: &Option<String>) -> proc_macro2::TokenStream {
+    let description = option_string(description.as_deref());
+
+    quote! {
+        _opg::Model {
+            description: #description,
+            example: None,
+            default: None,
+            read_only: false,
+            write_only: false,
+            data: _opg::ModelData::Null(_opg::ModelNull)
+        }
+    }
+}
+
 fn object_model<P>(
     nullable: bool,
     description: &Option<String>,
@@ -569,16 +791,63 @@ where
     P: Fn(&Field) -> bool,
 {
     let description = option_string(description.as_deref());
-    let object_type_description = object_type_description(fields, inline_predicate);
+    let object_model_data = object_model_data(
+        nullable,
+        fields,
+        &attr::SerdeDefault::None,
+        inline_predicate,
+    );
 
     quote! {
         _opg::Model {
             description: #description,
-            data: _opg::ModelData::Single(_opg::ModelType {
+            example: None,
+            default: None,
+            read_only: false,
+            write_only: false,
+            data: #object_model_data
+        }
+    }
+}
+
+fn object_model_data<P>(
+    nullable: bool,
+    fields: &[Field],
+    container_default: &attr::SerdeDefault,
+    inline_predicate: P,
+) -> proc_macro2::TokenStream
+where
+    P: Fn(&Field) -> bool,
+{
+    let object_type_description =
+        object_type_description(fields, container_default, &inline_predicate);
+
+    let flattened = fields
+        .iter()
+        .filter(|field| !field.attrs.skip_serializing && field.attrs.flatten)
+        .map(|field| {
+            field_model_reference(
+                ContextParams::from(&field.attrs),
+                field,
+                inline_predicate(field),
+            )
+        })
+        .collect::<Vec<_>>();
+
+    if flattened.is_empty() {
+        quote! {
+            _opg::ModelData::Single(_opg::ModelType {
                 nullable: #nullable,
                 type_description: #object_type_description
             })
         }
+    } else {
+        quote! {
+            _opg::ModelType {
+                nullable: #nullable,
+                type_description: #object_type_description
+            }.flatten(vec![#(#flattened),*])
+        }
     }
 }
 
@@ -606,8 +875,14 @@ where
     let one_of = quote! {
         _opg::Model {
             description: None,
+            example: None,
+            default: None,
+            read_only: false,
+            write_only: false,
             data: _opg::ModelData::OneOf(_opg::ModelOneOf {
                 one_of: vec![#(#data),*],
+                discriminator: None,
+                nullable: false,
             })
         }
     };
@@ -616,28 +891,152 @@ where
         _opg::ModelTypeDescription::Array(
             _opg::ModelArray {
                 items: Box::new(_opg::ModelReference::Inline(#one_of)),
+                tuple: true,
+                ..Default::default()
             }
         )
     }
 }
 
-fn object_type_description<P>(fields: &[Field], inline_predicate: P) -> proc_macro2::TokenStream
+fn object_type_description<P>(
+    fields: &[Field],
+    container_default: &attr::SerdeDefault,
+    inline_predicate: P,
+) -> proc_macro2::TokenStream
 where
     P: Fn(&Field) -> bool,
 {
-    let data = fields
+    let fields = fields
+        .iter()
+        .filter(|field| !field.attrs.skip() && !field.attrs.flatten)
+        .collect::<Vec<_>>();
+
+    let properties = if fields
         .iter()
-        .filter(|field| !field.attrs.skip_serializing)
+        .any(|field| field.attrs.is_directional(container_default))
+    {
+        let request = object_properties(
+            &fields,
+            container_default,
+            &inline_predicate,
+            Some(PropertiesDirection::Request),
+        );
+        let response = object_properties(
+            &fields,
+            container_default,
+            &inline_predicate,
+            Some(PropertiesDirection::Response),
+        );
+        let base = object_properties(&fields, container_default, &inline_predicate, None);
+
+        quote! {
+            match cx.direction() {
+                Some(_opg::SchemaDirection::Request) => {
+                    cx.mark_directional();
+                    #(#request)*
+                }
+                Some(_opg::SchemaDirection::Response) => {
+                    cx.mark_directional();
+                    #(#response)*
+                }
+                None => {
+                    #(#base)*
+                }
+            }
+        }
+    } else {
+        let base = object_properties(&fields, container_default, &inline_predicate, None);
+        quote!( #(#base)* )
+    };
+
+    quote! {
+        {
+            let mut properties = std::collections::BTreeMap::new();
+            let mut required = Vec::new();
+
+            #properties
+
+            _opg::ModelTypeDescription::Object(
+                _opg::ModelObject {
+                    properties,
+                    required,
+                    ..Default::default()
+                }
+            )
+        }
+    }
+}
+
+#[derive(Copy, Clone)]
+enum PropertiesDirection {
+    Request,
+    Response,
+}
+
+/// Generate properties insertion for the specified direction
+///
+/// Without direction, properties use serialized names and one-way fields are marked
+/// with `readOnly`/`writeOnly`
+fn object_properties<P>(
+    fields: &[&Field],
+    container_default: &attr::SerdeDefault,
+    inline_predicate: P,
+    direction: Option<PropertiesDirection>,
+) -> Vec<proc_macro2::TokenStream>
+where
+    P: Fn(&Field) -> bool,
+{
+    fields
+        .iter()
+        .filter(|field| match direction {
+            Some(PropertiesDirection::Request) => !field.attrs.skip_deserializing,
+            Some(PropertiesDirection::Response) => !field.attrs.skip_serializing,
+            None => true,
+        })
         .map(|field| {
-            let field_model_reference = field_model_reference(
+            let mut field_model_reference = field_model_reference(
                 ContextParams::from(&field.attrs),
                 field,
                 inline_predicate(field),
             );
 
-            let property_name = syn::LitStr::new(&field.attrs.name.serialized(), Span::call_site());
+            let property_name = match direction {
+                Some(PropertiesDirection::Request) => field.attrs.name.deserialized(),
+                _ => field.attrs.name.serialized(),
+            };
+            let property_name = syn::LitStr::new(&property_name, Span::call_site());
+
+            let request_only = direction.is_none() && field.attrs.skip_serializing;
+            let response_only = direction.is_none() && field.attrs.skip_deserializing;
+            if request_only {
+                field_model_reference = quote!( #field_model_reference.into_write_only() );
+            } else if response_only {
+                field_model_reference = quote!( #field_model_reference.into_read_only() );
+            }
 
-            let push_required = if !field.attrs.optional {
+            if let Some(default_value) = default_value(field, container_default) {
+                field_model_reference =
+                    quote!( #field_model_reference.with_default(#default_value) );
+            }
+
+            // Missing values are filled by serde only while deserializing
+            let filled_by_serde = field.attrs.is_defaulted(container_default)
+                && !matches!(direction, Some(PropertiesDirection::Response));
+
+            // Any of the aliases can be used instead of the name, so none of them is required
+            let aliases = match direction {
+                Some(PropertiesDirection::Request) => field
+                    .attrs
+                    .aliases
+                    .iter()
+                    .map(|alias| syn::LitStr::new(alias, Span::call_site()))
+                    .collect(),
+                _ => Vec::new(),
+            };
+
+            let required = !field.attrs.is_optional() && !filled_by_serde && aliases.is_empty();
+
+            let push_required = if required {
                 quote!( required.push(#property_name.to_owned()) )
             } else {
                 quote!()
@@ -645,27 +1044,48 @@ where
 
             quote! {
                 properties.insert(#property_name.to_owned(), #field_model_reference);
+                #(properties.insert(#aliases.to_owned(), properties[#property_name].clone());)*
                 #push_required;
             }
         })
-        .collect::<Vec<_>>();
+        .collect()
+}
 
-    quote! {
-        {
-            let mut properties = std::collections::BTreeMap::new();
-            let mut required = Vec::new();
+/// Generate expression which evaluates default value of the field as `Option<serde_json::Value>`
+fn default_value(
+    field: &Field,
+    container_default: &attr::SerdeDefault,
+) -> Option<proc_macro2::TokenStream> {
+    if let Some(default_value) = &field.attrs.default_value {
+        return Some(quote! { _opg::example_value(&(#default_value)) });
+    }
 
-            #(#data)*
+    let type_name = &field.original.ty;
+    let member = &field.member;
 
-            _opg::ModelTypeDescription::Object(
-                _opg::ModelObject {
-                    properties,
-                    required,
-                    ..Default::default()
-                }
-            )
+    let default_value = match (&field.attrs.default, container_default) {
+        (attr::SerdeDefault::Default, _) => quote! {
+            _opg::DefaultValue::<#type_name, _>::new(|value: #type_name| value)
+        },
+        (attr::SerdeDefault::Path(path), _) => quote! {
+            _opg::DefaultValue::<(), _>::new(|_: ()| #path())
+        },
+        (attr::SerdeDefault::None, attr::SerdeDefault::Default) => quote! {
+            _opg::DefaultValue::<Self, _>::new(|container: Self| container.#member)
+        },
+        (attr::SerdeDefault::None, attr::SerdeDefault::Path(path)) => quote! {
+            _opg::DefaultValue::<(), _>::new(|_: ()| #path().#member)
+        },
+        (attr::SerdeDefault::None, attr::SerdeDefault::None) => return None,
+    };
+
+    Some(quote! {
+        {
+            #[allow(unused_imports)]
+            use _opg::{OpaqueDefault as _, SerializableDefault as _};
+            (&#default_value).default_value()
         }
-    }
+    })
 }
 
 fn field_model_reference<'a>(
@@ -675,6 +1095,12 @@ fn field_model_reference<'a>(
 ) -> proc_macro2::TokenStream {
     let type_name = &field.original.ty;
 
+    if let Some(reference) =
+        schema_override_reference(field.attrs.schema_override.as_ref(), context_params, inline)
+    {
+        return reference;
+    }
+
     match field.attrs.explicit_model_type {
         Some(explicit_model_type) if explicit_model_type != ExplicitModelType::Any => {
             let model = newtype_model(field.attrs.nullable, context_params, explicit_model_type);
@@ -698,6 +1124,25 @@ fn field_model_reference<'a>(
     }
 }
 
+/// Reference to the hand-written schema of a field or variant
+fn schema_override_reference(
+    schema_override: Option<&attr::SchemaOverride>,
+    context_params: ContextParams,
+    inline: bool,
+) -> Option<proc_macro2::TokenStream> {
+    let schema_override = schema_override?;
+    let context_params = context_params.tokenize();
+
+    Some(match schema_override {
+        attr::SchemaOverride::With(path) => quote! {
+            #path(cx).apply_params(&#context_params)
+        },
+        attr::SchemaOverride::As(type_name) => quote! {
+            cx.mention_schema::<#type_name>(#inline, &#context_params)
+        },
+    })
+}
+
 fn newtype_model(
     nullable: bool,
     context_params: ContextParams,
@@ -711,20 +1156,21 @@ fn newtype_model(
                 variants: None,
                 data: _opg::ModelSimple {
                     format: #format,
-                    example: #example,
-                }
+                    ..Default::default()
+                },
+                ..Default::default()
             })
         },
         ExplicitModelType::Integer => quote! {
             _opg::ModelTypeDescription::Integer(_opg::ModelSimple {
                 format: #format,
-                example: #example,
+                ..Default::default()
             })
         },
         ExplicitModelType::Number => quote! {
             _opg::ModelTypeDescription::Number(_opg::ModelSimple {
                 format: #format,
-                example: #example,
+                ..Default::default()
             })
         },
         ExplicitModelType::Boolean => quote! {
@@ -733,21 +1179,42 @@ fn newtype_model(
         ExplicitModelType::Any => unreachable!(),
     };
 
-    quote! {
+    let model = quote! {
         _opg::Model {
             description: #description,
+            example: #example,
+            default: None,
+            read_only: false,
+            write_only: false,
             data: _opg::ModelData::Single(_opg::ModelType {
                 nullable: #nullable,
                 type_description: #data
             }),
         }
+    };
+
+    match context_params.validation {
+        Some(validation) => {
+            let validation_params = ContextParams::new().validation(Some(validation)).tokenize();
+            quote! { #model.apply_params(&#validation_params) }
+        }
+        None => model,
+    }
+}
+
+fn option_example_expr(data: Option<&syn::Expr>) -> proc_macro2::TokenStream {
+    match data {
+        Some(data) => {
+            quote! { _opg::example_value(&(#data)) }
+        }
+        None => quote! { None },
     }
 }
 
-fn option_to_string_expr(data: Option<&syn::Expr>) -> proc_macro2::TokenStream {
+fn option_example_str(data: Option<&str>) -> proc_macro2::TokenStream {
     match data {
         Some(data) => {
-            quote! { Some((#data).to_string()) }
+            quote! { Some(#data.into()) }
         }
         None => quote! { None },
     }
@@ -771,12 +1238,37 @@ fn option_bool(data: Option<bool>) -> proc_macro2::TokenStream {
     }
 }
 
+fn option_flag(data: Option<bool>) -> proc_macro2::TokenStream {
+    option_bool(data.filter(|flag| *flag))
+}
+
+fn option_value<T>(data: Option<T>) -> proc_macro2::TokenStream
+where
+    T: quote::ToTokens,
+{
+    match data {
+        Some(data) => {
+            quote! { Some(#data) }
+        }
+        None => quote! { None },
+    }
+}
+
+fn option_number(data: Option<attr::Number>) -> proc_macro2::TokenStream {
+    match data {
+        Some(attr::Number::Integer(value)) => {
+            quote! { Some(_opg::serde_json::Number::from(#value)) }
+        }
+        Some(attr::Number::Float(value)) => quote! { _opg::serde_json::Number::from_f64(#value) },
+        None => quote! { None },
+    }
+}
+
 fn implement_type(
-    type_name: &syn::Ident,
+    container: &Container,
     body: proc_macro2::TokenStream,
-    inline: bool,
 ) -> proc_macro2::TokenStream {
-    let inline = if inline {
+    let inline = if container.attrs.inline {
         quote! {
             #[inline]
             fn type_name() -> Option<std::borrow::Cow<'static, str>> {
@@ -789,14 +1281,26 @@ fn implement_type(
             }
         }
     } else {
+        let type_name = type_name(container);
+
         quote! {
             #[inline]
             fn type_name() -> Option<std::borrow::Cow<'static, str>> {
-                Some(stringify!(#type_name).into())
+                #type_name
             }
         }
     };
 
+    let body = if container.attrs.example.is_some() || container.attrs.validation.is_some() {
+        let container_params = ContextParams::new()
+            .example(container.attrs.example.as_ref())
+            .validation(container.attrs.validation.as_ref())
+            .tokenize();
+        quote! { (#body).apply_params(&#container_params) }
+    } else {
+        body
+    };
+
     quote! {
         fn get_schema(cx: &mut _opg::Components) -> _opg::Model {
             #body
@@ -806,12 +1310,75 @@ fn implement_type(
     }
 }
 
+fn type_name(container: &Container) -> proc_macro2::TokenStream {
+    let type_name = &container.ident;
+
+    let bounded_type_params = build_generics(container)
+        .where_clause
+        .map(|where_clause| {
+            where_clause
+                .predicates
+                .into_iter()
+                .filter_map(|predicate| match predicate {
+                    syn::WherePredicate::Type(syn::PredicateType {
+                        bounded_ty: syn::Type::Path(ty),
+                        ..
+                    }) => ty.path.get_ident().cloned(),
+                    _ => None,
+                })
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+
+    let arguments = container
+        .generics
+        .params
+        .iter()
+        .filter_map(|param| match param {
+            syn::GenericParam::Type(param) if bounded_type_params.contains(&param.ident) => {
+                let ident = &param.ident;
+                Some(quote! {
+                    <#ident as _opg::OpgModel>::type_name()
+                        .unwrap_or_else(_opg::short_type_name::<#ident>)
+                })
+            }
+            syn::GenericParam::Type(param) => {
+                let ident = &param.ident;
+                Some(quote! { _opg::short_type_name::<#ident>() })
+            }
+            syn::GenericParam::Const(param) => {
+                let ident = &param.ident;
+                Some(quote! { #ident.to_string() })
+            }
+            syn::GenericParam::Lifetime(_) => None,
+        })
+        .collect::<Vec<_>>();
+
+    if arguments.is_empty() {
+        return quote! {
+            Some(stringify!(#type_name).into())
+        };
+    }
+
+    let separator = container.attrs.generic_separator.as_deref().unwrap_or("_");
+
+    quote! {
+        let mut name = stringify!(#type_name).to_owned();
+        #(
+            name.push_str(#separator);
+            name.push_str(&#arguments);
+        )*
+        Some(name.into())
+    }
+}
+
 #[derive(Default, Copy, Clone)]
 struct ContextParams<'a> {
     description: Option<&'a str>,
     nullable: Option<bool>,
     format: Option<&'a str>,
     example: Option<&'a syn::Expr>,
+    validation: Option<&'a attr::Validation>,
 }
 
 impl<'a> From<&'a attr::Container> for ContextParams<'a> {
@@ -830,6 +1397,7 @@ impl<'a> From<&'a attr::Variant> for ContextParams<'a> {
             .description(attrs.description.as_deref())
             .format(attrs.format.as_deref())
             .example(attrs.example.as_ref())
+            .validation(attrs.validation.as_ref())
     }
 }
 
@@ -840,6 +1408,7 @@ impl<'a> From<&'a attr::Field> for ContextParams<'a> {
             .nullable(if attrs.nullable { Some(true) } else { None })
             .format(attrs.format.as_deref())
             .example(attrs.example.as_ref())
+            .validation(attrs.validation.as_ref())
     }
 }
 
@@ -868,6 +1437,11 @@ impl<'a> ContextParams<'a> {
         self
     }
 
+    fn validation(mut self, validation: Option<&'a attr::Validation>) -> Self {
+        self.validation = validation;
+        self
+    }
+
     fn or<T>(mut self, other: T) -> Self
     where
         T: Into<ContextParams<'a>>,
@@ -876,6 +1450,7 @@ impl<'a> ContextParams<'a> {
         self.description = self.description.or(other.description);
         self.format = self.format.or(other.format);
         self.example = self.example.or(other.example);
+        self.validation = self.validation.or(other.validation);
         self
     }
 
@@ -889,7 +1464,7 @@ impl<'a> ContextParams<'a> {
         (
             option_string(self.description),
             option_string(self.format),
-            option_to_string_expr(self.example),
+            option_example_expr(self.example),
         )
     }
 
@@ -897,7 +1472,22 @@ impl<'a> ContextParams<'a> {
         let description = option_string(self.description);
         let nullable = option_bool(self.nullable);
         let format = option_string(self.format);
-        let example = option_to_string_expr(self.example);
+        let example = option_example_expr(self.example);
+
+        let validation = self.validation;
+        let minimum = option_number(validation.and_then(|v| v.minimum));
+        let maximum = option_number(validation.and_then(|v| v.maximum));
+        let exclusive_minimum = option_flag(validation.map(|v| v.exclusive_minimum));
+        let exclusive_maximum = option_flag(validation.map(|v| v.exclusive_maximum));
+        let multiple_of = option_number(validation.and_then(|v| v.multiple_of));
+        let min_length = option_value(validation.and_then(|v| v.min_length));
+        let max_length = option_value(validation.and_then(|v| v.max_length));
+        let pattern = option_string(validation.and_then(|v| v.pattern.as_deref()));
+        let min_items = option_value(validation.and_then(|v| v.min_items));
+        let max_items = option_value(validation.and_then(|v| v.max_items));
+        let unique_items = option_flag(validation.map(|v| v.unique_items));
+        let min_properties = option_value(validation.and_then(|v| v.min_properties));
+        let max_properties = option_value(validation.and_then(|v| v.max_properties));
 
         quote! {
             _opg::ContextParams {
@@ -906,6 +1496,19 @@ impl<'a> ContextParams<'a> {
                 variants: None,
                 format: #format,
                 example: #example,
+                minimum: #minimum,
+                maximum: #maximum,
+                exclusive_minimum: #exclusive_minimum,
+                exclusive_maximum: #exclusive_maximum,
+                multiple_of: #multiple_of,
+                min_length: #min_length,
+                max_length: #max_length,
+                pattern: #pattern,
+                min_items: #min_items,
+                max_items: #max_items,
+                unique_items: #unique_items,
+                min_properties: #min_properties,
+                max_properties: #max_properties,
             }
         }
     }
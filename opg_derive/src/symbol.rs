@@ -14,8 +14,27 @@ define_symbols! {
     // named values
     EXAMPLE => "example",
     EXAMPLE_WITH => "example_with",
+    DEFAULT_WITH => "default_with",
+    SCHEMA_WITH => "schema_with",
+    AS => "as",
     FORMAT => "format",
     DESCRIPTION => "description",
+    GENERIC_SEPARATOR => "generic_separator",
+
+    // validation
+    MINIMUM => "minimum",
+    MAXIMUM => "maximum",
+    EXCLUSIVE_MINIMUM => "exclusive_minimum",
+    EXCLUSIVE_MAXIMUM => "exclusive_maximum",
+    MULTIPLE_OF => "multiple_of",
+    MIN_LENGTH => "min_length",
+    MAX_LENGTH => "max_length",
+    PATTERN => "pattern",
+    MIN_ITEMS => "min_items",
+    MAX_ITEMS => "max_items",
+    UNIQUE_ITEMS => "unique_items",
+    MIN_PROPERTIES => "min_properties",
+    MAX_PROPERTIES => "max_properties",
 
     // flags
     STRING => "string",
@@ -27,24 +46,28 @@ define_symbols! {
     INLINE => "inline",
     OPTIONAL => "optional",
     NULLABLE => "nullable",
+    SKIP_DOC => "skip_doc",
 
     // serde
     SERDE => "serde",
     UNTAGGED => "untagged",
-    TRANSPARENT => "transparent",
     FLATTEN => "flatten",
     SKIP => "skip",
     SKIP_SERIALIZING => "skip_serializing",
     SKIP_SERIALIZING_IF => "skip_serializing_if",
+    SKIP_DESERIALIZING => "skip_deserializing",
+    DEFAULT => "default",
     TAG => "tag",
     CONTENT => "content",
     RENAME => "rename",
     RENAME_ALL => "rename_all",
+    ALIAS => "alias",
     SERIALIZE => "serialize",
     DESERIALIZE => "deserialize",
 
     // misc
     REPR => "repr",
+    DOC => "doc",
 }
 
 #[derive(Copy, Clone)]
@@ -62,7 +85,7 @@ impl PartialEq<Symbol> for Ident {
     }
 }
 
-impl<'a> PartialEq<Symbol> for &'a Ident {
+impl PartialEq<Symbol> for &Ident {
     fn eq(&self, other: &Symbol) -> bool {
         *self == other.0
     }
@@ -74,7 +97,7 @@ impl PartialEq<Symbol> for Path {
     }
 }
 
-impl<'a> PartialEq<Symbol> for &'a Path {
+impl PartialEq<Symbol> for &Path {
     fn eq(&self, other: &Symbol) -> bool {
         self.is_ident(other.0)
     }
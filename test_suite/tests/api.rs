@@ -250,10 +250,9 @@ paths:
           content:
             application/json:
               schema:
-                description: "Always `null`"
                 nullable: true
-                type: string
-                format: "null"
+                enum:
+                  - ~
     parameters:
       - name: asd
         in: header
@@ -326,4 +325,1860 @@ components:
 "##
         );
     }
+
+    #[derive(Serialize, OpgModel)]
+    pub struct UploadForm {
+        name: String,
+        file: String,
+    }
+
+    #[test]
+    fn multiple_media_types() {
+        let test = describe_api! {
+            info: {
+                title: "Media types",
+                version: "0.0.0",
+            },
+            paths: {
+                ("upload"): {
+                    POST: {
+                        body: {
+                            content: {
+                                "multipart/form-data": {
+                                    schema: UploadForm,
+                                    encoding: {
+                                        file: {
+                                            content_type: "image/png",
+                                        },
+                                        "name": {
+                                            style: Form,
+                                            explode: true,
+                                        },
+                                    },
+                                },
+                                "application/x-www-form-urlencoded": UploadForm,
+                            },
+                        },
+                        200: {
+                            content: {
+                                "text/plain": String,
+                                "application/json": {
+                                    schema: String,
+                                    examples: {
+                                        first: {
+                                            summary: "First example",
+                                            value: "done",
+                                        },
+                                    },
+                                },
+                            },
+                        },
+                        204: None,
+                    }
+                }
+            }
+        };
+
+        assert_eq!(
+            serde_yaml::to_string(&test).unwrap(),
+            r##"---
+openapi: 3.0.3
+info:
+  title: Media types
+  version: 0.0.0
+paths:
+  /upload:
+    post:
+      requestBody:
+        required: true
+        description: ""
+        content:
+          multipart/form-data:
+            schema:
+              $ref: "#/components/schemas/UploadForm"
+            encoding:
+              file:
+                contentType: image/png
+              name:
+                style: form
+                explode: true
+          application/x-www-form-urlencoded:
+            schema:
+              $ref: "#/components/schemas/UploadForm"
+      responses:
+        200:
+          description: OK
+          content:
+            text/plain:
+              schema:
+                type: string
+            application/json:
+              schema:
+                type: string
+              examples:
+                first:
+                  summary: First example
+                  value: done
+        204:
+          description: No Content
+components:
+  schemas:
+    UploadForm:
+      type: object
+      properties:
+        file:
+          type: string
+        name:
+          type: string
+      required:
+        - name
+        - file
+"##
+        );
+    }
+
+    #[test]
+    fn response_headers_and_links() {
+        let test = describe_api! {
+            info: {
+                title: "Headers and links",
+                version: "0.0.0",
+            },
+            paths: {
+                ("users"): {
+                    POST: {
+                        operationId: "createUser",
+                        body: UploadForm,
+                        201: {
+                            headers: {
+                                "Location": {
+                                    description: "Created user url",
+                                    required: true,
+                                },
+                                "X-RateLimit-Remaining": u32,
+                            },
+                            links: {
+                                GetUser: {
+                                    operation_id: "getUser",
+                                    parameters: {
+                                        userId: "$response.header.Location",
+                                    },
+                                    description: "Get created user",
+                                },
+                            },
+                        },
+                        429: {
+                            headers: {
+                                "Retry-After": {
+                                    schema: u64,
+                                    description: "Seconds to wait",
+                                },
+                            },
+                        },
+                    }
+                }
+            }
+        };
+
+        assert_eq!(
+            serde_yaml::to_string(&test).unwrap(),
+            r##"---
+openapi: 3.0.3
+info:
+  title: Headers and links
+  version: 0.0.0
+paths:
+  /users:
+    post:
+      operationId: createUser
+      requestBody:
+        required: true
+        description: ""
+        content:
+          application/json:
+            schema:
+              $ref: "#/components/schemas/UploadForm"
+      responses:
+        201:
+          description: Created
+          headers:
+            Location:
+              description: Created user url
+              required: true
+              schema:
+                type: string
+            X-RateLimit-Remaining:
+              schema:
+                type: integer
+                format: uint32
+          links:
+            GetUser:
+              operationId: getUser
+              parameters:
+                userId: $response.header.Location
+              description: Get created user
+        429:
+          description: Too Many Requests
+          headers:
+            Retry-After:
+              description: Seconds to wait
+              schema:
+                type: integer
+                format: uint64
+components:
+  schemas:
+    UploadForm:
+      type: object
+      properties:
+        file:
+          type: string
+        name:
+          type: string
+      required:
+        - name
+        - file
+"##
+        );
+    }
+
+    #[test]
+    fn oauth2_and_open_id_connect() {
+        let test = describe_api! {
+            info: {
+                title: "Security schemes",
+                version: "0.0.0",
+            },
+            security_schemes: {
+                (oauth2 "petstoreAuth"): {
+                    description: "Petstore authorization",
+                    flows: {
+                        implicit: {
+                            authorization_url: "https://example.com/api/oauth/dialog",
+                            scopes: {
+                                "read:pets": "read your pets",
+                                "write:pets": "modify pets in your account",
+                            },
+                        },
+                        authorization_code: {
+                            authorization_url: "https://example.com/api/oauth/dialog",
+                            token_url: "https://example.com/api/oauth/token",
+                            refresh_url: "https://example.com/api/oauth/refresh",
+                            scopes: {
+                                "read:pets": "read your pets",
+                            },
+                        },
+                    },
+                },
+                (openIdConnect "oidc"): {
+                    open_id_connect_url: "https://example.com/.well-known/openid-configuration",
+                },
+            },
+            paths: {
+                ("pets"): {
+                    GET: {
+                        security: {
+                            "petstoreAuth"["read:pets", "write:pets"],
+                            "oidc"["profile"]
+                        },
+                        200: String,
+                    }
+                }
+            }
+        };
+
+        assert_eq!(
+            serde_yaml::to_string(&test).unwrap(),
+            r##"---
+openapi: 3.0.3
+info:
+  title: Security schemes
+  version: 0.0.0
+paths:
+  /pets:
+    get:
+      security:
+        - petstoreAuth:
+            - "read:pets"
+            - "write:pets"
+        - oidc:
+            - profile
+      responses:
+        200:
+          description: OK
+          content:
+            application/json:
+              schema:
+                type: string
+components:
+  securitySchemes:
+    oidc:
+      type: openIdConnect
+      openIdConnectUrl: "https://example.com/.well-known/openid-configuration"
+    petstoreAuth:
+      type: oauth2
+      flows:
+        implicit:
+          authorizationUrl: "https://example.com/api/oauth/dialog"
+          scopes:
+            "read:pets": read your pets
+            "write:pets": modify pets in your account
+        authorizationCode:
+          authorizationUrl: "https://example.com/api/oauth/dialog"
+          tokenUrl: "https://example.com/api/oauth/token"
+          refreshUrl: "https://example.com/api/oauth/refresh"
+          scopes:
+            "read:pets": read your pets
+      description: Petstore authorization
+"##
+        );
+    }
+
+    #[test]
+    fn undeclared_oauth2_scope() {
+        let test = describe_api! {
+            info: {
+                title: "Security schemes",
+                version: "0.0.0",
+            },
+            security_schemes: {
+                (oauth2 "petstoreAuth"): {
+                    flows: {
+                        client_credentials: {
+                            token_url: "https://example.com/api/oauth/token",
+                            scopes: {
+                                "read:pets": "read your pets",
+                            },
+                        },
+                    },
+                },
+            },
+            paths: {
+                ("pets"): {
+                    DELETE: {
+                        security: {
+                            "petstoreAuth"["admin"]
+                        },
+                        200: None,
+                    }
+                }
+            }
+        };
+
+        assert_eq!(
+            test.validate()
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>(),
+            vec![
+                "/paths/~1pets/delete/security/0/petstoreAuth/0: scope `admin` is not declared in security scheme `petstoreAuth`"
+            ]
+        );
+    }
+
+    #[test]
+    fn server_variables() {
+        let test = describe_api! {
+            info: {
+                title: "Servers",
+                version: "0.0.0",
+            },
+            servers: {
+                "https://api.example.com/v1"("Global server"),
+                ("https://{region}.api.example.com/{basePath}"): {
+                    description: "Regional server",
+                    variables: {
+                        region: {
+                            default: "eu",
+                            enum: ["eu", "us"],
+                            description: "Deployment region",
+                        },
+                        basePath: {
+                            default: "v1",
+                        },
+                    },
+                },
+            },
+            paths: {
+                ("ping"): {
+                    GET: {
+                        200: None,
+                    }
+                }
+            }
+        };
+
+        assert_eq!(
+            serde_yaml::to_string(&test).unwrap(),
+            r##"---
+openapi: 3.0.3
+info:
+  title: Servers
+  version: 0.0.0
+servers:
+  - url: "https://api.example.com/v1"
+    description: Global server
+  - url: "https://{region}.api.example.com/{basePath}"
+    description: Regional server
+    variables:
+      basePath:
+        default: v1
+      region:
+        enum:
+          - eu
+          - us
+        default: eu
+        description: Deployment region
+paths:
+  /ping:
+    get:
+      responses:
+        200:
+          description: OK
+components: {}
+"##
+        );
+    }
+
+    #[test]
+    fn undeclared_server_variable() {
+        let test = describe_api! {
+            info: {
+                title: "Servers",
+                version: "0.0.0",
+            },
+            servers: {
+                ("https://{tenant}.example.com"): {
+                    description: "Tenant server",
+                },
+                ("https://{region}.example.com"): {
+                    variables: {
+                        region: {
+                            default: "asia",
+                            enum: ["eu", "us"],
+                        },
+                    },
+                },
+            },
+            paths: {
+                ("ping"): {
+                    GET: {
+                        200: None,
+                    }
+                }
+            }
+        };
+
+        assert_eq!(
+            test.validate()
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>(),
+            vec![
+                "/servers/0/url: server variable `tenant` is not declared",
+                "/servers/1/variables/region/default: default value of server variable `region` is not one of its variants",
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_existing_document() {
+        let document = r##"---
+openapi: 3.0.3
+info:
+  title: Pets
+  version: 1.0.0
+tags:
+  - name: pets
+    description: Pet operations
+servers:
+  - url: "https://{env}.example.com"
+    variables:
+      env:
+        enum:
+          - api
+          - staging
+        default: api
+paths:
+  /pets:
+    get:
+      tags:
+        - pets
+      operationId: listPets
+      security:
+        - oauth:
+            - "read:pets"
+      responses:
+        200:
+          description: OK
+          content:
+            application/json:
+              schema:
+                type: array
+                items:
+                  $ref: "#/components/schemas/Pet"
+      parameters:
+        - name: limit
+          in: query
+          schema:
+            type: integer
+            format: int32
+            minimum: 1.0
+  "/pets/{petId}":
+    summary: Single pet
+    get:
+      operationId: getPet
+      responses:
+        200:
+          description: OK
+          content:
+            application/json:
+              schema:
+                $ref: "#/components/schemas/Pet"
+      callbacks:
+        onUpdate:
+          /updated:
+            post:
+              responses:
+                200:
+                  description: OK
+    parameters:
+      - name: petId
+        in: path
+        required: true
+        schema:
+          type: string
+components:
+  schemas:
+    Kind:
+      type: string
+      enum:
+        - cat
+        - dog
+    Pet:
+      description: A pet
+      type: object
+      properties:
+        kind:
+          $ref: "#/components/schemas/Kind"
+        name:
+          nullable: true
+          type: string
+          maxLength: 32
+        owner:
+          oneOf:
+            - type: string
+            - type: integer
+        tags:
+          type: object
+          additionalProperties: {}
+      required:
+        - kind
+      example:
+        kind: cat
+        name: Tom
+  securitySchemes:
+    oauth:
+      type: oauth2
+      flows:
+        implicit:
+          authorizationUrl: "https://example.com/auth"
+          scopes:
+            "read:pets": Read pets
+"##;
+
+        let parsed: Opg = serde_yaml::from_str(document).unwrap();
+        assert_eq!(parsed.paths.len(), 2);
+        assert_eq!(parsed.validate(), Vec::new());
+
+        assert_eq!(serde_yaml::to_string(&parsed).unwrap(), document);
+    }
+
+    #[test]
+    fn parse_generated_document() {
+        let generated = describe_api! {
+            info: {
+                title: "Round trip",
+                version: "0.0.0",
+            },
+            tags: {internal},
+            paths: {
+                ("test" / { id: u32 }): {
+                    POST: {
+                        tags: {internal},
+                        body: request::InModule,
+                        200: SimpleEnum,
+                        404: None,
+                    }
+                }
+            }
+        };
+
+        let yaml = serde_yaml::to_string(&generated).unwrap();
+        let parsed: Opg = serde_yaml::from_str(&yaml).unwrap();
+
+        assert_eq!(serde_yaml::to_string(&parsed).unwrap(), yaml);
+    }
+
+    #[test]
+    fn response_code_keys() {
+        let parsed: Opg = serde_json::from_str(
+            r#"{
+                "openapi": "3.0.3",
+                "info": { "title": "Test", "version": "0.0.0" },
+                "paths": {
+                    "/test": {
+                        "get": {
+                            "responses": {
+                                "default": { "description": "Error" },
+                                "4XX": { "description": "Client error" },
+                                "404": { "description": "Not found" },
+                                "200": { "description": "OK" }
+                            }
+                        }
+                    }
+                }
+            }"#,
+        )
+        .unwrap();
+
+        let operation = &parsed.paths[0].1.operations[&HttpMethod::GET];
+        assert_eq!(
+            operation.responses.keys().collect::<Vec<_>>(),
+            vec![
+                &ResponseCode::Status(200),
+                &ResponseCode::Status(404),
+                &ResponseCode::Range(4),
+                &ResponseCode::Default
+            ]
+        );
+
+        assert_eq!(
+            serde_json::to_value(&operation.responses).unwrap(),
+            serde_json::json!({
+                "200": { "description": "OK" },
+                "404": { "description": "Not found" },
+                "4XX": { "description": "Client error" },
+                "default": { "description": "Error" }
+            })
+        );
+
+        assert!(serde_json::from_str::<ResponseCode>(r#""2xx""#).is_err());
+        assert!(serde_json::from_str::<ResponseCode>(r#""other""#).is_err());
+    }
+
+    #[test]
+    fn unsupported_path_item_fields() {
+        for (field, value) in &[
+            ("$ref", r##""#/paths/~1other""##),
+            ("servers", r#"[{ "url": "https://example.com" }]"#),
+        ] {
+            let error = serde_json::from_str::<Opg>(&format!(
+                r#"{{
+                    "openapi": "3.0.3",
+                    "info": {{ "title": "Test", "version": "0.0.0" }},
+                    "paths": {{ "/test": {{ "{}": {} }} }}
+                }}"#,
+                field, value
+            ))
+            .unwrap_err();
+            assert!(error
+                .to_string()
+                .contains(&format!("`{}` in path items is not supported", field)));
+        }
+    }
+
+    mod users {
+        use super::*;
+
+        #[derive(Serialize, OpgModel)]
+        pub struct User {
+            id: u32,
+        }
+
+        #[derive(Serialize, OpgModel)]
+        pub struct Group {
+            owner: User,
+        }
+    }
+
+    mod accounts {
+        use super::*;
+
+        #[derive(Serialize, OpgModel)]
+        pub struct User {
+            name: String,
+        }
+
+        #[derive(Serialize, OpgModel)]
+        pub struct Group {
+            owner: User,
+        }
+    }
+
+    fn users_api() -> Opg {
+        describe_api! {
+            info: {
+                title: "Users",
+                version: "0.0.0",
+            },
+            tags: {users},
+            servers: {
+                "https://users.example.com"
+            },
+            paths: {
+                ("users"): {
+                    GET: {
+                        tags: {users},
+                        200: users::User,
+                    }
+                }
+            }
+        }
+    }
+
+    fn accounts_api() -> Opg {
+        describe_api! {
+            info: {
+                title: "Accounts",
+                version: "0.0.0",
+            },
+            tags: {accounts},
+            paths: {
+                ("users"): {
+                    POST: {
+                        tags: {accounts},
+                        body: accounts::User,
+                        200: SimpleEnum,
+                    }
+                },
+                ("accounts"): {
+                    GET: {
+                        tags: {accounts},
+                        200: accounts::User,
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn merge_with_renames() {
+        let mut api = users_api();
+        api.merge(
+            accounts_api(),
+            &MergePolicy::RenameWithPrefix("Accounts".to_owned()),
+        )
+        .unwrap();
+
+        assert_eq!(
+            serde_yaml::to_string(&api).unwrap(),
+            r##"---
+openapi: 3.0.3
+info:
+  title: Users
+  version: 0.0.0
+tags:
+  - name: accounts
+  - name: users
+servers:
+  - url: "https://users.example.com"
+paths:
+  /users:
+    get:
+      tags:
+        - users
+      responses:
+        200:
+          description: OK
+          content:
+            application/json:
+              schema:
+                $ref: "#/components/schemas/User"
+    post:
+      tags:
+        - accounts
+      requestBody:
+        required: true
+        description: ""
+        content:
+          application/json:
+            schema:
+              $ref: "#/components/schemas/AccountsUser"
+      responses:
+        200:
+          description: OK
+          content:
+            application/json:
+              schema:
+                $ref: "#/components/schemas/SimpleEnum"
+  /accounts:
+    get:
+      tags:
+        - accounts
+      responses:
+        200:
+          description: OK
+          content:
+            application/json:
+              schema:
+                $ref: "#/components/schemas/AccountsUser"
+components:
+  schemas:
+    AccountsUser:
+      type: object
+      properties:
+        name:
+          type: string
+      required:
+        - name
+    SimpleEnum:
+      description: Simple enum
+      type: string
+      enum:
+        - test
+        - another
+        - yay
+      example: test
+    User:
+      type: object
+      properties:
+        id:
+          type: integer
+          format: uint32
+      required:
+        - id
+"##
+        );
+    }
+
+    #[test]
+    fn merge_conflicts() {
+        let mut api = users_api();
+        let error = api.merge(accounts_api(), &MergePolicy::Error).unwrap_err();
+        assert_eq!(error.to_string(), "schema `User` is already defined");
+        assert_eq!(api.paths.len(), 1);
+
+        let mut api = users_api();
+        api.merge(accounts_api(), &MergePolicy::KeepFirst).unwrap();
+        assert_eq!(api.paths.len(), 2);
+        assert_eq!(api.components.schemas.len(), 2);
+        assert!(matches!(
+            &api.components.schemas["User"].data,
+            ModelData::Single(ModelType {
+                type_description: ModelTypeDescription::Object(object),
+                ..
+            }) if object.properties.contains_key("id")
+        ));
+
+        let mut api = users_api();
+        let error = api
+            .merge(
+                users_api(),
+                &MergePolicy::RenameWithPrefix("Copy".to_owned()),
+            )
+            .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "operation `get /users` is already defined"
+        );
+    }
+
+    #[test]
+    fn merge_transitive_renames() {
+        let mut api = describe_api! {
+            info: {
+                title: "Users",
+                version: "0.0.0",
+            },
+            paths: {
+                ("groups"): {
+                    GET: {
+                        200: users::Group,
+                    }
+                }
+            }
+        };
+
+        let other = describe_api! {
+            info: {
+                title: "Accounts",
+                version: "0.0.0",
+            },
+            paths: {
+                ("account-groups"): {
+                    GET: {
+                        200: accounts::Group,
+                    }
+                }
+            }
+        };
+
+        api.merge(other, &MergePolicy::RenameWithPrefix("Accounts".to_owned()))
+            .unwrap();
+
+        assert_eq!(
+            serde_yaml::to_string(&api.components).unwrap(),
+            r##"---
+schemas:
+  AccountsGroup:
+    type: object
+    properties:
+      owner:
+        $ref: "#/components/schemas/AccountsUser"
+    required:
+      - owner
+  AccountsUser:
+    type: object
+    properties:
+      name:
+        type: string
+    required:
+      - name
+  Group:
+    type: object
+    properties:
+      owner:
+        $ref: "#/components/schemas/User"
+    required:
+      - owner
+  User:
+    type: object
+    properties:
+      id:
+        type: integer
+        format: uint32
+    required:
+      - id
+"##
+        );
+
+        // schemas are equal once links of the merged document are renamed
+        let mut api = users_api();
+        api.components.schemas.insert(
+            "Group".to_owned(),
+            serde_yaml::from_str(
+                r##"---
+type: object
+properties:
+  owner:
+    $ref: "#/components/schemas/CopyUser"
+required:
+  - owner
+"##,
+            )
+            .unwrap(),
+        );
+        let mut other = Opg::default();
+        other.components.schemas = api.components.schemas.clone();
+        other.components.schemas.insert(
+            "User".to_owned(),
+            accounts_api().components.schemas["User"].clone(),
+        );
+        other.components.schemas.insert(
+            "Group".to_owned(),
+            serde_yaml::from_str(
+                r##"---
+type: object
+properties:
+  owner:
+    $ref: "#/components/schemas/User"
+required:
+  - owner
+"##,
+            )
+            .unwrap(),
+        );
+
+        api.merge(other, &MergePolicy::RenameWithPrefix("Copy".to_owned()))
+            .unwrap();
+        assert_eq!(
+            api.components.schemas.keys().collect::<Vec<_>>(),
+            vec!["CopyUser", "Group", "User"]
+        );
+    }
+
+    #[test]
+    fn merge_path_and_webhook_conflicts() {
+        let document = |path: &str, webhook_method: &str| -> Opg {
+            serde_yaml::from_str(&format!(
+                r##"---
+openapi: 3.0.3
+info:
+  title: Test
+  version: 0.0.0
+paths:
+  "{}":
+    get:
+      responses:
+        200:
+          description: OK
+webhooks:
+  userCreated:
+    {}:
+      responses:
+        200:
+          description: OK
+"##,
+                path, webhook_method
+            ))
+            .unwrap()
+        };
+
+        let mut api = document("/users/{id}", "post");
+        let error = api
+            .merge(document("/users/{userId}", "put"), &MergePolicy::Error)
+            .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "path `/users/{userId}` is identical to `/users/{id}`"
+        );
+
+        let mut api = document("/users/{id}", "post");
+        api.merge(document("/users/{userId}", "put"), &MergePolicy::KeepFirst)
+            .unwrap();
+        assert_eq!(api.paths.len(), 1);
+        assert_eq!(api.paths[0].0.to_string(), "/users/{id}");
+        assert_eq!(api.webhooks["userCreated"].operations.len(), 2);
+
+        let mut api = document("/users/{id}", "post");
+        let error = api
+            .merge(document("/users", "post"), &MergePolicy::Error)
+            .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "webhook operation `post userCreated` is already defined"
+        );
+        assert!(api.paths.len() == 1 && api.webhooks["userCreated"].operations.len() == 1);
+    }
+
+    #[test]
+    fn validate_document() {
+        assert_eq!(users_api().validate(), Vec::new());
+        assert_eq!(accounts_api().validate(), Vec::new());
+
+        let document: Opg = serde_yaml::from_str(
+            r##"---
+openapi: 3.0.3
+info:
+  title: Invalid
+  version: 0.0.0
+tags:
+  - name: pets
+paths:
+  "/pets/{petId}":
+    get:
+      tags:
+        - pets
+        - animals
+      operationId: getPet
+      security:
+        - oauth:
+            - "read:pets"
+            - "write:pets"
+          apiKey: []
+      responses:
+        200:
+          description: OK
+          content:
+            application/json:
+              schema:
+                $ref: "#/components/schemas/Pet"
+    parameters:
+      - name: id
+        in: path
+        schema:
+          type: integer
+          format: date
+  "/pets/{id}":
+    post:
+      operationId: getPet
+      responses:
+        200:
+          description: OK
+    parameters:
+      - name: id
+        in: path
+        required: true
+components:
+  schemas:
+    Kind:
+      type: string
+      format: int32
+      enum:
+        - cat
+        - dog
+        - cat
+    Interval:
+      type: string
+      format: interval
+  securitySchemes:
+    oauth:
+      type: oauth2
+      flows:
+        implicit:
+          authorizationUrl: "https://example.com/auth"
+          scopes:
+            "read:pets": Read pets
+        password:
+          scopes: {}
+        clientCredentials:
+          authorizationUrl: "https://example.com/auth"
+          scopes: {}
+        authorizationCode:
+          tokenUrl: "https://example.com/token"
+          scopes: {}
+    missingImplicitUrl:
+      type: oauth2
+      flows:
+        implicit:
+          tokenUrl: "https://example.com/token"
+          scopes: {}
+"##,
+        )
+        .unwrap();
+
+        assert_eq!(
+            document
+                .validate()
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>(),
+            vec![
+                "/paths/~1pets~1{petId}/parameters/0: path parameter `id` must be required",
+                "/paths/~1pets~1{petId}/parameters/0: path parameter `id` is not used in path",
+                "/paths/~1pets~1{petId}/parameters/0/schema/format: format `date` is not applicable to integer",
+                "/paths/~1pets~1{petId}/get: path parameter `petId` is not declared",
+                "/paths/~1pets~1{petId}/get/tags/1: tag `animals` is not declared",
+                "/paths/~1pets~1{petId}/get/security/0/apiKey: security scheme `apiKey` is not declared",
+                "/paths/~1pets~1{petId}/get/security/0/oauth/1: scope `write:pets` is not declared in security scheme `oauth`",
+                "/paths/~1pets~1{petId}/get/responses/200/content/application~1json/schema: schema `Pet` is not defined",
+                "/paths/~1pets~1{id}: path `/pets/{id}` is already defined",
+                "/paths/~1pets~1{id}/post/operationId: operation id `getPet` is already used",
+                "/components/schemas/Kind/enum/2: enum variant `cat` is duplicated",
+                "/components/schemas/Kind/format: format `int32` is not applicable to string",
+                "/components/securitySchemes/missingImplicitUrl/flows/implicit/authorizationUrl: `implicit` flow requires `authorizationUrl`",
+                "/components/securitySchemes/oauth/flows/password/tokenUrl: `password` flow requires `tokenUrl`",
+                "/components/securitySchemes/oauth/flows/clientCredentials/tokenUrl: `clientCredentials` flow requires `tokenUrl`",
+                "/components/securitySchemes/oauth/flows/authorizationCode/authorizationUrl: `authorizationCode` flow requires `authorizationUrl`",
+            ]
+        );
+    }
+
+    #[derive(Serialize, OpgModel)]
+    #[serde(tag = "type")]
+    enum Shelter {
+        Cat { name: String },
+        Dog { name: String },
+    }
+
+    #[derive(Serialize, OpgModel)]
+    struct ShelterCat {
+        lives: u8,
+    }
+
+    #[test]
+    fn variant_schema_name_collision() {
+        let api = describe_api! {
+            info: {
+                title: "Shelter",
+                version: "0.0.0",
+            },
+            paths: {
+                ("cats"): {
+                    GET: {
+                        200: ShelterCat,
+                    }
+                },
+                ("pets"): {
+                    GET: {
+                        200: Shelter,
+                    }
+                }
+            }
+        };
+
+        assert_eq!(
+            api.validate()
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>(),
+            vec![
+                "/components/schemas/ShelterCat: schema name `ShelterCat` is used by several types"
+            ]
+        );
+
+        let api = describe_api! {
+            info: {
+                title: "Shelter",
+                version: "0.0.0",
+            },
+            paths: {
+                ("pets"): {
+                    GET: {
+                        200: Shelter,
+                    }
+                },
+                ("cats"): {
+                    GET: {
+                        200: ShelterCat,
+                    }
+                }
+            }
+        };
+
+        assert_eq!(
+            api.validate()
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>(),
+            vec![
+                "/components/schemas/ShelterCat: schema name `ShelterCat` is used by several types"
+            ]
+        );
+    }
+
+    #[derive(Serialize, OpgModel)]
+    #[serde(tag = "kind", rename_all = "camelCase")]
+    enum PetEvent {
+        Created {
+            name: Option<String>,
+        },
+        Moved {
+            position: (f32, f32),
+        },
+        Tagged {
+            #[opg(min_items = 2, max_items = 2, inline)]
+            tags: Vec<PetTag>,
+            #[opg(inline)]
+            mood: Option<PetMood>,
+        },
+    }
+
+    #[derive(Serialize, OpgModel)]
+    #[serde(untagged)]
+    #[opg(inline)]
+    enum PetTag {
+        Id(u32),
+        Name(String),
+    }
+
+    #[derive(Serialize, OpgModel)]
+    #[serde(rename_all = "camelCase")]
+    enum PetMood {
+        Happy,
+    }
+
+    #[derive(Serialize, OpgModel)]
+    struct PetAge(#[opg(minimum = 0, exclusive_minimum, example = 3)] u32);
+
+    #[test]
+    fn openapi_31_dialect() {
+        let mut api = describe_api! {
+            dialect: { OpenApi31 },
+            info: {
+                title: "Pets",
+                version: "0.0.0",
+            },
+            paths: {
+                ("pets" / { age: PetAge }): {
+                    GET: {
+                        200: PetEvent,
+                    }
+                }
+            },
+            webhooks: {
+                petEvent: {
+                    POST: {
+                        body: PetEvent,
+                        200: None,
+                    }
+                }
+            }
+        };
+
+        api.components.schemas.insert(
+            "Pet".to_owned(),
+            Model {
+                description: Some("Pet event".to_owned()),
+                data: ModelData::AllOf(ModelAllOf {
+                    all_of: vec![ModelReference::Link("PetEvent".to_owned())],
+                    nullable: false,
+                }),
+                example: None,
+                default: None,
+                read_only: false,
+                write_only: false,
+            },
+        );
+
+        assert_eq!(
+            serde_yaml::to_string(&api).unwrap(),
+            r##"---
+openapi: 3.1.0
+info:
+  title: Pets
+  version: 0.0.0
+jsonSchemaDialect: "https://spec.openapis.org/oas/3.1/dialect/base"
+paths:
+  "/pets/{age}":
+    get:
+      responses:
+        "200":
+          description: OK
+          content:
+            application/json:
+              schema:
+                $ref: "#/components/schemas/PetEvent"
+    parameters:
+      - name: age
+        in: path
+        required: true
+        schema:
+          $ref: "#/components/schemas/PetAge"
+webhooks:
+  petEvent:
+    post:
+      requestBody:
+        required: true
+        description: ""
+        content:
+          application/json:
+            schema:
+              $ref: "#/components/schemas/PetEvent"
+      responses:
+        "200":
+          description: OK
+components:
+  schemas:
+    Pet:
+      description: Pet event
+      $ref: "#/components/schemas/PetEvent"
+    PetAge:
+      type: integer
+      format: uint32
+      examples:
+        - 3
+      exclusiveMinimum: 0
+    PetEvent:
+      oneOf:
+        - $ref: "#/components/schemas/PetEventCreated"
+        - $ref: "#/components/schemas/PetEventMoved"
+        - $ref: "#/components/schemas/PetEventTagged"
+      discriminator:
+        propertyName: kind
+        mapping:
+          created: "#/components/schemas/PetEventCreated"
+          moved: "#/components/schemas/PetEventMoved"
+          tagged: "#/components/schemas/PetEventTagged"
+    PetEventCreated:
+      type: object
+      properties:
+        kind:
+          description: PetEvent type variant
+          type: string
+          examples:
+            - created
+          const: created
+        name:
+          type:
+            - string
+            - "null"
+      required:
+        - name
+        - kind
+    PetEventMoved:
+      type: object
+      properties:
+        kind:
+          description: PetEvent type variant
+          type: string
+          examples:
+            - moved
+          const: moved
+        position:
+          type: array
+          items: false
+          prefixItems:
+            - type: number
+              format: float
+            - type: number
+              format: float
+          minItems: 2
+      required:
+        - position
+        - kind
+    PetEventTagged:
+      type: object
+      properties:
+        kind:
+          description: PetEvent type variant
+          type: string
+          examples:
+            - tagged
+          const: tagged
+        mood:
+          type:
+            - string
+            - "null"
+          enum:
+            - happy
+            - ~
+          examples:
+            - happy
+        tags:
+          type: array
+          items:
+            oneOf:
+              - type: integer
+                format: uint32
+              - type: string
+          minItems: 2
+          maxItems: 2
+      required:
+        - tags
+        - mood
+        - kind
+"##
+        );
+
+        api.dialect = OpenApiDialect::OpenApi30;
+        let yaml = serde_yaml::to_string(&api).unwrap();
+        assert!(yaml.starts_with("---\nopenapi: 3.0.3\n"));
+        assert!(!yaml.contains("webhooks"));
+        assert!(!yaml.contains("jsonSchemaDialect"));
+        assert!(yaml.contains("nullable: true"));
+    }
+
+    #[derive(Serialize, OpgModel)]
+    struct PetOwner {
+        name: Option<String>,
+        pets: Vec<PetAge>,
+    }
+
+    #[test]
+    fn swagger2_export() {
+        let api = describe_api! {
+            info: {
+                title: "Pets",
+                version: "0.0.0",
+            },
+            servers: {
+                "https://pets.example.com/api/v1",
+                "http://localhost:8080"
+            },
+            security_schemes: {
+                (http "bearerAuth"): {
+                    scheme: Bearer,
+                    bearer_format: "JWT",
+                },
+                (http "basicAuth"): {
+                    scheme: Basic,
+                    description: "Basic auth"
+                },
+                (apiKey "cookieAuth"): {
+                    parameter_in: Cookie,
+                    name: "session",
+                }
+            },
+            paths: {
+                ("owners" / { id: u32 }): {
+                    summary: "Pet owner",
+                    parameters: {
+                        (header "x-request-id")
+                    },
+                    POST: {
+                        security: { "basicAuth" },
+                        parameters: {
+                            (query age: PetAge),
+                            (query owner: PetOwner)
+                        },
+                        body: PetOwner,
+                        200: PetEvent,
+                        callbacks: {
+                            created: {
+                                ("callback"): {
+                                    POST: {
+                                        200: None,
+                                    }
+                                }
+                            }
+                        }
+                    },
+                    PUT: {
+                        body: {
+                            content: {
+                                "multipart/form-data": {
+                                    schema: PetOwner,
+                                    encoding: {
+                                        pets: {
+                                            content_type: "application/json",
+                                        },
+                                    },
+                                },
+                            },
+                        },
+                        200: {
+                            content: {
+                                "application/json": {
+                                    schema: PetOwner,
+                                    examples: {
+                                        empty: {
+                                            value: "{}",
+                                        },
+                                    },
+                                },
+                            },
+                        },
+                    }
+                }
+            }
+        };
+
+        let swagger = api.to_swagger2();
+
+        assert_eq!(
+            serde_yaml::to_string(&swagger.document).unwrap(),
+            r##"---
+swagger: "2.0"
+info:
+  title: Pets
+  version: 0.0.0
+host: pets.example.com
+basePath: /api/v1
+schemes:
+  - https
+paths:
+  "/owners/{id}":
+    put:
+      consumes:
+        - multipart/form-data
+      produces:
+        - application/json
+      parameters:
+        - name: body
+          in: body
+          required: true
+          schema:
+            $ref: "#/definitions/PetOwner"
+      responses:
+        "200":
+          description: OK
+          schema:
+            $ref: "#/definitions/PetOwner"
+    post:
+      security:
+        - basicAuth: []
+      consumes:
+        - application/json
+      produces:
+        - application/json
+      parameters:
+        - name: age
+          in: query
+          type: integer
+          format: uint32
+          minimum: 0
+          exclusiveMinimum: true
+        - name: owner
+          in: query
+          type: string
+        - name: body
+          in: body
+          required: true
+          schema:
+            $ref: "#/definitions/PetOwner"
+      responses:
+        "200":
+          description: OK
+          schema:
+            $ref: "#/definitions/PetEvent"
+    parameters:
+      - name: id
+        in: path
+        required: true
+        type: integer
+        format: uint32
+      - name: x-request-id
+        in: header
+        required: true
+        type: string
+definitions:
+  PetAge:
+    type: integer
+    format: uint32
+    minimum: 0
+    exclusiveMinimum: true
+    example: 3
+  PetEvent: {}
+  PetEventCreated:
+    type: object
+    properties:
+      kind:
+        description: PetEvent type variant
+        type: string
+        enum:
+          - created
+        example: created
+      name:
+        type: string
+        x-nullable: true
+    required:
+      - name
+      - kind
+  PetEventMoved:
+    type: object
+    properties:
+      kind:
+        description: PetEvent type variant
+        type: string
+        enum:
+          - moved
+        example: moved
+      position:
+        type: array
+        items: {}
+    required:
+      - position
+      - kind
+  PetEventTagged:
+    type: object
+    properties:
+      kind:
+        description: PetEvent type variant
+        type: string
+        enum:
+          - tagged
+        example: tagged
+      mood:
+        type: string
+        enum:
+          - happy
+        example: happy
+        x-nullable: true
+      tags:
+        type: array
+        items: {}
+        minItems: 2
+        maxItems: 2
+    required:
+      - tags
+      - mood
+      - kind
+  PetOwner:
+    type: object
+    properties:
+      name:
+        type: string
+        x-nullable: true
+      pets:
+        type: array
+        items:
+          $ref: "#/definitions/PetAge"
+    required:
+      - name
+      - pets
+securityDefinitions:
+  basicAuth:
+    type: basic
+    description: Basic auth
+  bearerAuth:
+    type: apiKey
+    in: header
+    name: Authorization
+"##
+        );
+
+        assert_eq!(
+            swagger
+                .warnings
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>(),
+            vec![
+                "/servers: only the first server is used",
+                "/paths/~1owners~1{id}/summary: path item `summary` is not supported",
+                "/paths/~1owners~1{id}/put/requestBody/content/multipart~1form-data/encoding: encoding is not supported",
+                "/paths/~1owners~1{id}/put/responses/200/content/application~1json/examples: media type examples are not supported",
+                "/paths/~1owners~1{id}/post/parameters/1/schema: schema of `owner` must be a primitive type or an array",
+                "/paths/~1owners~1{id}/post/callbacks: callbacks are not supported",
+                "/components/schemas/PetEvent/oneOf: `oneOf` is not supported and was dropped",
+                "/components/schemas/PetEventMoved/properties/position/items/oneOf: `oneOf` is not supported and was dropped",
+                "/components/schemas/PetEventTagged/properties/tags/items/oneOf: `oneOf` is not supported and was dropped",
+                "/components/securitySchemes/bearerAuth: bearer security scheme `bearerAuth` is replaced with `Authorization` header",
+                "/components/securitySchemes/cookieAuth: security scheme `cookieAuth` is not supported",
+            ]
+        );
+    }
+
+    #[derive(Serialize, Deserialize, OpgModel)]
+    struct Credentials {
+        login: String,
+        #[serde(skip_serializing)]
+        password: String,
+    }
+
+    #[derive(Serialize, Deserialize, OpgModel)]
+    struct Session {
+        #[serde(rename(deserialize = "refreshToken"))]
+        token: String,
+        #[serde(default)]
+        scopes: Vec<String>,
+    }
+
+    #[test]
+    fn directional_schemas() {
+        let api = describe_api! {
+            info: {
+                title: "Sessions",
+                version: "0.0.0",
+            },
+            paths: {
+                ("login"): {
+                    POST: {
+                        body: Credentials,
+                        200: Session,
+                    }
+                },
+                ("refresh"): {
+                    POST: {
+                        body: Session,
+                        200: Session,
+                    }
+                }
+            }
+        };
+
+        assert_eq!(
+            serde_yaml::to_string(&api).unwrap(),
+            r##"---
+openapi: 3.0.3
+info:
+  title: Sessions
+  version: 0.0.0
+paths:
+  /login:
+    post:
+      requestBody:
+        required: true
+        description: ""
+        content:
+          application/json:
+            schema:
+              $ref: "#/components/schemas/Credentials"
+      responses:
+        200:
+          description: OK
+          content:
+            application/json:
+              schema:
+                $ref: "#/components/schemas/SessionResponse"
+  /refresh:
+    post:
+      requestBody:
+        required: true
+        description: ""
+        content:
+          application/json:
+            schema:
+              $ref: "#/components/schemas/SessionRequest"
+      responses:
+        200:
+          description: OK
+          content:
+            application/json:
+              schema:
+                $ref: "#/components/schemas/SessionResponse"
+components:
+  schemas:
+    Credentials:
+      type: object
+      properties:
+        login:
+          type: string
+        password:
+          type: string
+          writeOnly: true
+      required:
+        - login
+        - password
+    SessionRequest:
+      type: object
+      properties:
+        refreshToken:
+          type: string
+        scopes:
+          type: array
+          items:
+            type: string
+          default: []
+      required:
+        - refreshToken
+    SessionResponse:
+      type: object
+      properties:
+        scopes:
+          type: array
+          items:
+            type: string
+          default: []
+        token:
+          type: string
+      required:
+        - token
+        - scopes
+"##
+        );
+    }
+
+    #[test]
+    fn json_output() {
+        let api = describe_api! {
+            info: {
+                title: "Events",
+                version: "0.0.0",
+            },
+            paths: {
+                ("events"): {
+                    GET: {
+                        200: serde_json::Value,
+                    }
+                }
+            }
+        };
+
+        assert_eq!(
+            api.to_json_string_pretty().unwrap(),
+            r##"{
+  "openapi": "3.0.3",
+  "info": {
+    "title": "Events",
+    "version": "0.0.0"
+  },
+  "paths": {
+    "/events": {
+      "get": {
+        "responses": {
+          "200": {
+            "description": "OK",
+            "content": {
+              "application/json": {
+                "schema": {}
+              }
+            }
+          }
+        }
+      }
+    }
+  },
+  "components": {}
+}"##
+        );
+
+        let value = api.to_json_value().unwrap();
+        assert_eq!(
+            value["paths"]["/events"]["get"]["responses"]["200"]["content"]["application/json"]
+                ["schema"],
+            serde_json::json!({})
+        );
+    }
 }
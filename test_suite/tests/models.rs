@@ -177,35 +177,52 @@ oneOf:
             serde_yaml::to_string(&InternallyTaggedEnum::get_schema(cx)).unwrap(),
             r##"---
 oneOf:
-  - type: object
-    properties:
-      asd:
-        type: integer
-        format: int32
-      helloCamelCase:
-        $ref: "#/components/schemas/NewType"
-      tag:
-        description: InternallyTaggedEnum type variant
-        type: string
-        enum:
-          - test
-        example: test
-    required:
-      - asd
-      - tag
-  - type: object
-    properties:
-      field:
-        type: string
-      tag:
-        description: InternallyTaggedEnum type variant
-        type: string
-        enum:
-          - another-test
-        example: another-test
-    required:
-      - field
-      - tag
+  - $ref: "#/components/schemas/InternallyTaggedEnumTest"
+  - $ref: "#/components/schemas/InternallyTaggedEnumAnotherTest"
+discriminator:
+  propertyName: tag
+  mapping:
+    another-test: "#/components/schemas/InternallyTaggedEnumAnotherTest"
+    test: "#/components/schemas/InternallyTaggedEnumTest"
+"##
+        );
+        assert_eq!(
+            serde_yaml::to_string(&cx.schemas["InternallyTaggedEnumTest"]).unwrap(),
+            r##"---
+type: object
+properties:
+  asd:
+    type: integer
+    format: int32
+  helloCamelCase:
+    $ref: "#/components/schemas/NewType"
+  tag:
+    description: InternallyTaggedEnum type variant
+    type: string
+    enum:
+      - test
+    example: test
+required:
+  - asd
+  - tag
+"##
+        );
+        assert_eq!(
+            serde_yaml::to_string(&cx.schemas["InternallyTaggedEnumAnotherTest"]).unwrap(),
+            r##"---
+type: object
+properties:
+  field:
+    type: string
+  tag:
+    description: InternallyTaggedEnum type variant
+    type: string
+    enum:
+      - another-test
+    example: another-test
+required:
+  - field
+  - tag
 "##
         );
     }
@@ -215,6 +232,11 @@ oneOf:
     enum AdjacentlyTaggedEnum {
         Test(String),
         AnotherTest(NewType, NewType),
+        Empty,
+        #[opg("Struct variant")]
+        Named {
+            field: String,
+        },
     }
 
     #[test]
@@ -223,23 +245,76 @@ oneOf:
         assert_eq!(
             serde_yaml::to_string(&AdjacentlyTaggedEnum::get_schema(cx)).unwrap(),
             r##"---
+oneOf:
+  - $ref: "#/components/schemas/AdjacentlyTaggedEnumTest"
+  - $ref: "#/components/schemas/AdjacentlyTaggedEnumAnotherTest"
+  - $ref: "#/components/schemas/AdjacentlyTaggedEnumEmpty"
+  - $ref: "#/components/schemas/AdjacentlyTaggedEnumNamed"
+discriminator:
+  propertyName: tag
+  mapping:
+    another-test: "#/components/schemas/AdjacentlyTaggedEnumAnotherTest"
+    empty: "#/components/schemas/AdjacentlyTaggedEnumEmpty"
+    named: "#/components/schemas/AdjacentlyTaggedEnumNamed"
+    test: "#/components/schemas/AdjacentlyTaggedEnumTest"
+"##
+        );
+        assert_eq!(
+            serde_yaml::to_string(&cx.schemas["AdjacentlyTaggedEnumAnotherTest"]).unwrap(),
+            r##"---
 type: object
 properties:
   content:
-    oneOf:
-      - type: string
-      - type: array
-        items:
-          oneOf:
-            - $ref: "#/components/schemas/NewType"
-            - $ref: "#/components/schemas/NewType"
+    type: array
+    items:
+      oneOf:
+        - $ref: "#/components/schemas/NewType"
+        - $ref: "#/components/schemas/NewType"
   tag:
     description: AdjacentlyTaggedEnum type variant
     type: string
     enum:
-      - test
       - another-test
-    example: test
+    example: another-test
+required:
+  - tag
+  - content
+"##
+        );
+        assert_eq!(
+            serde_yaml::to_string(&cx.schemas["AdjacentlyTaggedEnumEmpty"]).unwrap(),
+            r##"---
+type: object
+properties:
+  tag:
+    description: AdjacentlyTaggedEnum type variant
+    type: string
+    enum:
+      - empty
+    example: empty
+required:
+  - tag
+"##
+        );
+        assert_eq!(
+            serde_yaml::to_string(&cx.schemas["AdjacentlyTaggedEnumNamed"]).unwrap(),
+            r##"---
+description: Struct variant
+type: object
+properties:
+  content:
+    type: object
+    properties:
+      field:
+        type: string
+    required:
+      - field
+  tag:
+    description: AdjacentlyTaggedEnum type variant
+    type: string
+    enum:
+      - named
+    example: named
 required:
   - tag
   - content
@@ -247,6 +322,52 @@ required:
         );
     }
 
+    #[derive(Serialize, OpgModel)]
+    #[serde(tag = "type")]
+    #[opg(inline)]
+    enum InlineTaggedEnum {
+        First { value: u8 },
+        Second,
+    }
+
+    #[test]
+    fn inline_tagged_enum() {
+        let cx = &mut Components::default();
+        assert_eq!(
+            serde_yaml::to_string(&InlineTaggedEnum::get_schema(cx)).unwrap(),
+            r##"---
+oneOf:
+  - type: object
+    properties:
+      type:
+        description: InlineTaggedEnum type variant
+        type: string
+        enum:
+          - First
+        example: First
+      value:
+        type: integer
+        format: uint8
+    required:
+      - value
+      - type
+  - type: object
+    properties:
+      type:
+        description: InlineTaggedEnum type variant
+        type: string
+        enum:
+          - Second
+        example: Second
+    required:
+      - type
+discriminator:
+  propertyName: type
+"##
+        );
+        assert!(cx.schemas.is_empty());
+    }
+
     #[derive(Serialize, OpgModel)]
     #[serde(rename_all = "camelCase")]
     struct TypeChangedStruct {
@@ -378,18 +499,135 @@ required:
         recursive_field: Option<Box<Recursive>>,
     }
 
+    #[derive(Serialize, OpgModel)]
+    struct Tree {
+        children: Vec<Tree>,
+    }
+
+    #[test]
     fn recursive_field() {
         let cx = &mut Components::default();
+        cx.mention_schema::<Recursive>(false, &Default::default());
+        cx.mention_schema::<Tree>(false, &Default::default());
+        assert_eq!(cx.verify_schemas(), Ok(()));
+
         assert_eq!(
-            serde_yaml::to_string(&Recursive::get_schema(cx)).unwrap(),
+            serde_yaml::to_string(cx).unwrap(),
             r##"---
-type: object
-properties:
-  field:
-    nullable: true
-    type: integer
-required:
-  - field
+schemas:
+  Recursive:
+    type: object
+    properties:
+      recursive_field:
+        allOf:
+          - $ref: "#/components/schemas/Recursive"
+        nullable: true
+    required:
+      - recursive_field
+  Tree:
+    type: object
+    properties:
+      children:
+        type: array
+        items:
+          $ref: "#/components/schemas/Tree"
+    required:
+      - children
+"##
+        );
+    }
+
+    #[derive(Serialize, OpgModel)]
+    struct Department {
+        head: Employee,
+    }
+
+    #[derive(Serialize, OpgModel)]
+    struct Employee {
+        departments: Vec<Department>,
+    }
+
+    #[test]
+    fn mutually_recursive_types() {
+        let cx = &mut Components::default();
+        cx.mention_schema::<Department>(false, &Default::default());
+        assert_eq!(cx.verify_schemas(), Ok(()));
+
+        assert_eq!(
+            serde_yaml::to_string(cx).unwrap(),
+            r##"---
+schemas:
+  Department:
+    type: object
+    properties:
+      head:
+        $ref: "#/components/schemas/Employee"
+    required:
+      - head
+  Employee:
+    type: object
+    properties:
+      departments:
+        type: array
+        items:
+          $ref: "#/components/schemas/Department"
+    required:
+      - departments
+"##
+        );
+    }
+
+    #[derive(Serialize, OpgModel)]
+    struct LinkedNode<T> {
+        value: T,
+        next: Option<Box<LinkedNode<T>>>,
+    }
+
+    #[test]
+    fn generic_recursive_type() {
+        let cx = &mut Components::default();
+        cx.mention_schema::<LinkedNode<u32>>(false, &Default::default());
+        cx.mention_schema::<LinkedNode<Tree>>(false, &Default::default());
+        assert_eq!(cx.verify_schemas(), Ok(()));
+
+        assert_eq!(
+            serde_yaml::to_string(cx).unwrap(),
+            r##"---
+schemas:
+  LinkedNode_Tree:
+    type: object
+    properties:
+      next:
+        allOf:
+          - $ref: "#/components/schemas/LinkedNode_Tree"
+        nullable: true
+      value:
+        $ref: "#/components/schemas/Tree"
+    required:
+      - value
+      - next
+  LinkedNode_u32:
+    type: object
+    properties:
+      next:
+        allOf:
+          - $ref: "#/components/schemas/LinkedNode_u32"
+        nullable: true
+      value:
+        type: integer
+        format: uint32
+    required:
+      - value
+      - next
+  Tree:
+    type: object
+    properties:
+      children:
+        type: array
+        items:
+          $ref: "#/components/schemas/Tree"
+    required:
+      - children
 "##
         );
     }
@@ -436,7 +674,142 @@ required:
             r##"---
 type: integer
 format: uint64
-example: "1599083980"
+example: 1599083980
+"##
+        );
+    }
+
+    #[derive(Debug, Serialize, OpgModel)]
+    #[opg(example_with = "ExampleObject::example()")]
+    struct ExampleObject {
+        id: u32,
+        tags: Vec<String>,
+    }
+
+    impl ExampleObject {
+        fn example() -> Self {
+            Self {
+                id: 1,
+                tags: vec!["first".to_owned(), "second".to_owned()],
+            }
+        }
+    }
+
+    #[derive(Debug, Serialize, OpgModel)]
+    #[serde(untagged)]
+    #[opg(example_with = "ExampleOneOf::Number(10)")]
+    enum ExampleOneOf {
+        Number(u32),
+        Text(String),
+    }
+
+    #[derive(Debug, Serialize, OpgModel)]
+    struct StructWithExamples {
+        #[opg(example = 42)]
+        count: u32,
+        #[opg(example = true)]
+        flag: bool,
+        #[opg(example_with = "example_values()")]
+        values: Vec<u32>,
+        #[opg(inline)]
+        object: ExampleObject,
+    }
+
+    fn example_values() -> Vec<u32> {
+        vec![1, 2, 3]
+    }
+
+    #[test]
+    fn typed_examples() {
+        let cx = &mut Components::default();
+        assert_eq!(
+            serde_yaml::to_string(&StructWithExamples::get_schema(cx)).unwrap(),
+            r##"---
+type: object
+properties:
+  count:
+    type: integer
+    format: uint32
+    example: 42
+  flag:
+    type: boolean
+    example: true
+  object:
+    type: object
+    properties:
+      id:
+        type: integer
+        format: uint32
+      tags:
+        type: array
+        items:
+          type: string
+    required:
+      - id
+      - tags
+    example:
+      id: 1
+      tags:
+        - first
+        - second
+  values:
+    type: array
+    items:
+      type: integer
+      format: uint32
+    example:
+      - 1
+      - 2
+      - 3
+required:
+  - count
+  - flag
+  - values
+  - object
+"##
+        );
+
+        assert_eq!(
+            serde_yaml::to_string(&ExampleOneOf::get_schema(cx)).unwrap(),
+            r##"---
+oneOf:
+  - type: integer
+    format: uint32
+  - type: string
+example: 10
+"##
+        );
+    }
+
+    #[derive(Debug, Serialize, OpgModel)]
+    struct StructWithUnrepresentableExample {
+        #[opg(example_with = "unrepresentable_example()")]
+        pairs: std::collections::BTreeMap<String, u32>,
+    }
+
+    fn unrepresentable_example() -> std::collections::BTreeMap<(u32, u32), u32> {
+        std::iter::once(((1, 2), 3)).collect()
+    }
+
+    #[test]
+    #[cfg_attr(
+        debug_assertions,
+        should_panic(expected = "example can't be serialized into JSON")
+    )]
+    fn unrepresentable_example_is_omitted() {
+        let cx = &mut Components::default();
+        assert_eq!(
+            serde_yaml::to_string(&StructWithUnrepresentableExample::get_schema(cx)).unwrap(),
+            r##"---
+type: object
+properties:
+  pairs:
+    type: object
+    additionalProperties:
+      type: integer
+      format: uint32
+required:
+  - pairs
 "##
         );
     }
@@ -492,8 +865,96 @@ required:
     }
 
     #[derive(Debug, Serialize, OpgModel)]
-    struct StructWithAny {
-        #[opg(any)]
+    struct Page<T> {
+        items: Vec<T>,
+        total: u32,
+    }
+
+    #[derive(Debug, Serialize, OpgModel)]
+    #[opg(generic_separator = "Of")]
+    struct ApiResponse<T> {
+        data: T,
+    }
+
+    #[test]
+    fn generic_type_names() {
+        assert_eq!(
+            Page::<SimpleStruct>::type_name().as_deref(),
+            Some("Page_SimpleStruct")
+        );
+        assert_eq!(Page::<u64>::type_name().as_deref(), Some("Page_u64"));
+        assert_eq!(
+            Page::<Vec<SimpleStruct>>::type_name().as_deref(),
+            Some("Page_Vec_SimpleStruct")
+        );
+        assert_eq!(
+            ApiResponse::<Page<NewType>>::type_name().as_deref(),
+            Some("ApiResponseOfPage_NewType")
+        );
+        assert_eq!(
+            GenericStructWithRef::<'static, i32>::type_name().as_deref(),
+            Some("GenericStructWithRef_i32")
+        );
+    }
+
+    #[test]
+    fn generic_struct_instantiations() {
+        let cx = &mut Components::default();
+        cx.mention_schema::<Page<NewType>>(false, &Default::default());
+        cx.mention_schema::<Page<SimpleStruct>>(false, &Default::default());
+
+        assert_eq!(
+            serde_yaml::to_string(&cx).unwrap(),
+            r##"---
+schemas:
+  NewType:
+    description: New type description
+    type: string
+    format: uuid
+    example: 000-000
+  Page_NewType:
+    type: object
+    properties:
+      items:
+        type: array
+        items:
+          $ref: "#/components/schemas/NewType"
+      total:
+        type: integer
+        format: uint32
+    required:
+      - items
+      - total
+  Page_SimpleStruct:
+    type: object
+    properties:
+      items:
+        type: array
+        items:
+          $ref: "#/components/schemas/SimpleStruct"
+      total:
+        type: integer
+        format: uint32
+    required:
+      - items
+      - total
+  SimpleStruct:
+    type: object
+    properties:
+      asd:
+        type: integer
+        format: int32
+      helloCamelCase:
+        $ref: "#/components/schemas/NewType"
+    required:
+      - asd
+"##
+        );
+    }
+
+    #[derive(Debug, Serialize, OpgModel)]
+    struct StructWithAny {
+        #[opg(any)]
         field: serde_yaml::Value,
     }
 
@@ -512,16 +973,158 @@ required:
         );
     }
 
+    #[derive(Debug, Serialize, OpgModel)]
+    #[opg(min_properties = 1)]
+    struct StructWithValidation {
+        #[opg(minimum = 1, maximum = 10, exclusive_maximum)]
+        count: u32,
+        #[opg(multiple_of = 0.5)]
+        ratio: f64,
+        #[opg(min_length = 3, max_length = 32, pattern = "^[a-z]+$")]
+        name: String,
+        #[opg(min_items = 1, unique_items)]
+        tags: Vec<String>,
+    }
+
+    #[derive(Debug, Serialize, OpgModel)]
+    #[opg("Percent value", minimum = 0, maximum = 100)]
+    struct Percent(u8);
+
+    #[test]
+    fn validation_keywords() {
+        let cx = &mut Components::default();
+        assert_eq!(
+            serde_yaml::to_string(&StructWithValidation::get_schema(cx)).unwrap(),
+            r##"---
+type: object
+properties:
+  count:
+    type: integer
+    format: uint32
+    minimum: 1
+    maximum: 10
+    exclusiveMaximum: true
+  name:
+    type: string
+    minLength: 3
+    maxLength: 32
+    pattern: "^[a-z]+$"
+  ratio:
+    type: number
+    format: double
+    multipleOf: 0.5
+  tags:
+    type: array
+    items:
+      type: string
+    minItems: 1
+    uniqueItems: true
+required:
+  - count
+  - ratio
+  - name
+  - tags
+minProperties: 1
+"##
+        );
+
+        assert_eq!(
+            serde_yaml::to_string(&Percent::get_schema(cx)).unwrap(),
+            r##"---
+description: Percent value
+type: integer
+format: uint8
+minimum: 0
+maximum: 100
+"##
+        );
+    }
+
+    /// Documented struct
+    ///
+    /// Second paragraph
+    #[derive(Debug, Serialize, OpgModel)]
+    struct DocumentedStruct {
+        /// Field from doc comment
+        documented: i32,
+        /// Overridden doc comment
+        #[opg("Explicit description")]
+        overridden: i32,
+        /// Skipped doc comment
+        #[opg(skip_doc)]
+        skipped: i32,
+        #[opg(inline)]
+        untagged: DocumentedEnum,
+    }
+
+    /// Documented enum
+    #[derive(Debug, Serialize, OpgModel)]
+    #[serde(untagged)]
+    #[opg(skip_doc)]
+    enum DocumentedEnum {
+        /// First variant
+        First {
+            value: i32,
+        },
+        Second {
+            value: String,
+        },
+    }
+
+    #[test]
+    fn doc_comments() {
+        let cx = &mut Components::default();
+        assert_eq!(
+            serde_yaml::to_string(&DocumentedStruct::get_schema(cx)).unwrap(),
+            r##"---
+description: "Documented struct\n\nSecond paragraph"
+type: object
+properties:
+  documented:
+    description: Field from doc comment
+    type: integer
+    format: int32
+  overridden:
+    description: Explicit description
+    type: integer
+    format: int32
+  skipped:
+    type: integer
+    format: int32
+  untagged:
+    oneOf:
+      - description: First variant
+        type: object
+        properties:
+          value:
+            type: integer
+            format: int32
+        required:
+          - value
+      - type: object
+        properties:
+          value:
+            type: string
+        required:
+          - value
+required:
+  - documented
+  - overridden
+  - skipped
+  - untagged
+"##
+        );
+    }
+
     #[test]
     fn null_response() {
         let cx = &mut Components::default();
         assert_eq!(
             serde_yaml::to_string(&<()>::get_schema(cx)).unwrap(),
             r##"---
-description: "Always `null`"
 nullable: true
-type: string
-format: "null"
+enum:
+  - ~
 "##
         );
     }
@@ -577,6 +1180,10 @@ additionalProperties:
 
         let model = Model {
             description: Some("Some type".to_owned()),
+            example: None,
+            default: None,
+            read_only: false,
+            write_only: false,
             data: ModelData::Single(ModelType {
                 nullable: false,
                 type_description: ModelTypeDescription::Object(ModelObject {
@@ -590,14 +1197,19 @@ additionalProperties:
                             "amount".to_owned(),
                             ModelReference::Inline(Model {
                                 description: None,
+                                example: None,
+                                default: None,
+                                read_only: false,
+                                write_only: false,
                                 data: ModelData::Single(ModelType {
                                     nullable: false,
                                     type_description: ModelTypeDescription::String(ModelString {
                                         variants: None,
                                         data: ModelSimple {
                                             format: None,
-                                            example: None,
+                                            ..Default::default()
                                         },
+                                        ..Default::default()
                                     }),
                                 }),
                             }),
@@ -613,6 +1225,7 @@ additionalProperties:
                         "paymentType".to_owned(),
                         "status".to_owned(),
                     ],
+                    ..Default::default()
                 }),
             }),
         };
@@ -755,4 +1368,1632 @@ required:
 
         assert_eq!(cx.verify_schemas(), Err(invalid_link.to_owned()));
     }
+
+    #[derive(Serialize, OpgModel)]
+    struct Pagination {
+        page: u32,
+        per_page: Option<u32>,
+    }
+
+    #[derive(Serialize, OpgModel)]
+    #[serde(tag = "kind", rename_all = "snake_case")]
+    enum Filter {
+        ByName { name: String },
+        ById { id: u32 },
+    }
+
+    #[derive(Serialize, OpgModel)]
+    struct FlattenedFields {
+        id: u32,
+        #[serde(flatten)]
+        #[opg(inline)]
+        pagination: Pagination,
+        #[serde(flatten)]
+        extra: std::collections::HashMap<String, String>,
+    }
+
+    #[derive(Serialize, OpgModel)]
+    struct FlattenedReferences {
+        limit: u32,
+        #[serde(flatten)]
+        pagination: Pagination,
+        #[serde(flatten)]
+        filter: Filter,
+    }
+
+    #[test]
+    fn flattened_fields() {
+        let cx = &mut Components::default();
+        assert_eq!(
+            serde_yaml::to_string(&FlattenedFields::get_schema(cx)).unwrap(),
+            r##"---
+type: object
+properties:
+  id:
+    type: integer
+    format: uint32
+  page:
+    type: integer
+    format: uint32
+  per_page:
+    nullable: true
+    type: integer
+    format: uint32
+additionalProperties:
+  type: string
+required:
+  - id
+  - page
+  - per_page
+"##
+        );
+        assert!(cx.schemas.is_empty());
+
+        assert_eq!(
+            serde_yaml::to_string(&FlattenedReferences::get_schema(cx)).unwrap(),
+            r##"---
+allOf:
+  - type: object
+    properties:
+      limit:
+        type: integer
+        format: uint32
+    required:
+      - limit
+  - $ref: "#/components/schemas/Pagination"
+  - $ref: "#/components/schemas/Filter"
+"##
+        );
+        assert!(cx.contains_model("Pagination"));
+        assert!(cx.contains_model("Filter"));
+    }
+
+    #[derive(Serialize, OpgModel)]
+    #[opg("Service config")]
+    struct ServiceConfig {
+        name: String,
+        port: Option<u16>,
+        upstream: Upstream,
+    }
+
+    #[derive(Serialize, OpgModel)]
+    struct Upstream {
+        url: String,
+        weights: (u8, u8),
+    }
+
+    #[test]
+    fn standalone_json_schema() {
+        assert_eq!(
+            serde_yaml::to_string(&opg::json_schema::<ServiceConfig>()).unwrap(),
+            r##"---
+$schema: "https://json-schema.org/draft/2020-12/schema"
+$ref: "#/$defs/ServiceConfig"
+$defs:
+  ServiceConfig:
+    description: Service config
+    type: object
+    properties:
+      name:
+        type: string
+      port:
+        type:
+          - integer
+          - "null"
+        format: uint16
+      upstream:
+        $ref: "#/$defs/Upstream"
+    required:
+      - name
+      - port
+      - upstream
+  Upstream:
+    type: object
+    properties:
+      url:
+        type: string
+      weights:
+        type: array
+        items: false
+        prefixItems:
+          - type: integer
+            format: uint8
+          - type: integer
+            format: uint8
+        minItems: 2
+    required:
+      - url
+      - weights
+"##
+        );
+
+        assert_eq!(
+            serde_yaml::to_string(&opg::json_schema_with_prefix::<Vec<Upstream>, _>(
+                "#/properties/config/$defs/"
+            ))
+            .unwrap(),
+            r##"---
+$schema: "https://json-schema.org/draft/2020-12/schema"
+type: array
+items:
+  $ref: "#/properties/config/$defs/Upstream"
+$defs:
+  Upstream:
+    type: object
+    properties:
+      url:
+        type: string
+      weights:
+        type: array
+        items: false
+        prefixItems:
+          - type: integer
+            format: uint8
+          - type: integer
+            format: uint8
+        minItems: 2
+    required:
+      - url
+      - weights
+"##
+        );
+
+        let schema = opg::json_schema_with_prefix::<InternallyTaggedEnum, _>("#/definitions/");
+        assert_eq!(
+            schema["$defs"]["InternallyTaggedEnum"]["discriminator"]["mapping"]["test"],
+            "#/definitions/InternallyTaggedEnumTest"
+        );
+        assert_eq!(
+            schema["$defs"]["InternallyTaggedEnum"]["oneOf"][0]["$ref"],
+            "#/definitions/InternallyTaggedEnumTest"
+        );
+
+        assert_eq!(
+            serde_yaml::to_string(&opg::json_schema::<u32>()).unwrap(),
+            r##"---
+$schema: "https://json-schema.org/draft/2020-12/schema"
+type: integer
+format: uint32
+"##
+        );
+    }
+
+    #[derive(Serialize, OpgModel)]
+    struct KeywordFields {
+        default: Upstream,
+        #[serde(rename = "enum")]
+        variants: Upstream,
+        #[serde(rename = "const")]
+        constant: Upstream,
+        examples: Upstream,
+    }
+
+    #[test]
+    fn json_schema_keyword_fields() {
+        let schema = opg::json_schema_with_prefix::<KeywordFields, _>("#/definitions/");
+        let properties = &schema["$defs"]["KeywordFields"]["properties"];
+        for field in &["default", "enum", "const", "examples"] {
+            assert_eq!(properties[*field]["$ref"], "#/definitions/Upstream");
+        }
+    }
+
+    #[derive(Serialize, serde::Deserialize, OpgModel)]
+    struct Account {
+        #[serde(skip_deserializing)]
+        id: u64,
+        email: String,
+        #[serde(skip_serializing)]
+        password: String,
+        #[serde(skip)]
+        cache: Option<String>,
+    }
+
+    #[derive(Serialize, serde::Deserialize, OpgModel)]
+    #[serde(rename_all(deserialize = "camelCase"))]
+    struct Profile {
+        display_name: String,
+        #[serde(default)]
+        tags: Vec<String>,
+        #[serde(skip_deserializing)]
+        created_at: u64,
+    }
+
+    #[derive(Serialize, serde::Deserialize, OpgModel)]
+    struct Contact {
+        #[serde(alias = "mail", alias = "e-mail")]
+        email: String,
+        phone: Option<String>,
+    }
+
+    #[derive(Serialize, serde::Deserialize, OpgModel)]
+    struct AccountProfile {
+        account: Account,
+        profile: Profile,
+    }
+
+    #[test]
+    fn directional_fields() {
+        let cx = &mut Components::new();
+        cx.mention_schema::<AccountProfile>(false, &Default::default());
+
+        assert_eq!(
+            serde_yaml::to_string(cx).unwrap(),
+            r##"---
+schemas:
+  Account:
+    type: object
+    properties:
+      email:
+        type: string
+      id:
+        type: integer
+        format: uint64
+        readOnly: true
+      password:
+        type: string
+        writeOnly: true
+    required:
+      - id
+      - email
+      - password
+  AccountProfile:
+    type: object
+    properties:
+      account:
+        $ref: "#/components/schemas/Account"
+      profile:
+        $ref: "#/components/schemas/Profile"
+    required:
+      - account
+      - profile
+  Profile:
+    type: object
+    properties:
+      created_at:
+        type: integer
+        format: uint64
+        readOnly: true
+      display_name:
+        type: string
+      tags:
+        type: array
+        items:
+          type: string
+        default: []
+    required:
+      - display_name
+      - created_at
+"##
+        );
+
+        let cx = &mut Components::new();
+        cx.set_direction(Some(opg::SchemaDirection::Request));
+        cx.mention_schema::<AccountProfile>(false, &Default::default());
+        cx.set_direction(Some(opg::SchemaDirection::Response));
+        cx.mention_schema::<AccountProfile>(false, &Default::default());
+        cx.mention_schema::<Account>(false, &Default::default());
+
+        assert_eq!(
+            serde_yaml::to_string(cx).unwrap(),
+            r##"---
+schemas:
+  Account:
+    type: object
+    properties:
+      email:
+        type: string
+      id:
+        type: integer
+        format: uint64
+        readOnly: true
+      password:
+        type: string
+        writeOnly: true
+    required:
+      - id
+      - email
+      - password
+  AccountProfileRequest:
+    type: object
+    properties:
+      account:
+        $ref: "#/components/schemas/Account"
+      profile:
+        $ref: "#/components/schemas/ProfileRequest"
+    required:
+      - account
+      - profile
+  AccountProfileResponse:
+    type: object
+    properties:
+      account:
+        $ref: "#/components/schemas/Account"
+      profile:
+        $ref: "#/components/schemas/ProfileResponse"
+    required:
+      - account
+      - profile
+  ProfileRequest:
+    type: object
+    properties:
+      displayName:
+        type: string
+      tags:
+        type: array
+        items:
+          type: string
+        default: []
+    required:
+      - displayName
+  ProfileResponse:
+    type: object
+    properties:
+      created_at:
+        type: integer
+        format: uint64
+      display_name:
+        type: string
+      tags:
+        type: array
+        items:
+          type: string
+        default: []
+    required:
+      - display_name
+      - tags
+      - created_at
+"##
+        );
+    }
+
+    #[derive(Serialize, serde::Deserialize, OpgModel)]
+    struct NodeA {
+        #[serde(default)]
+        tags: Vec<String>,
+        b: Option<Box<NodeB>>,
+    }
+
+    #[derive(Serialize, serde::Deserialize, OpgModel)]
+    struct NodeB {
+        a: Vec<NodeA>,
+    }
+
+    #[test]
+    fn directional_aliases() {
+        let cx = &mut Components::new();
+        cx.set_direction(Some(opg::SchemaDirection::Request));
+        cx.mention_schema::<Contact>(false, &Default::default());
+        cx.set_direction(Some(opg::SchemaDirection::Response));
+        cx.mention_schema::<Contact>(false, &Default::default());
+
+        assert_eq!(
+            serde_yaml::to_string(cx).unwrap(),
+            r##"---
+schemas:
+  ContactRequest:
+    type: object
+    properties:
+      e-mail:
+        type: string
+      email:
+        type: string
+      mail:
+        type: string
+      phone:
+        nullable: true
+        type: string
+    required:
+      - phone
+  ContactResponse:
+    type: object
+    properties:
+      email:
+        type: string
+      phone:
+        nullable: true
+        type: string
+    required:
+      - email
+      - phone
+"##
+        );
+    }
+
+    #[test]
+    fn directional_recursion() {
+        let cx = &mut Components::new();
+        cx.set_direction(Some(opg::SchemaDirection::Request));
+        cx.mention_schema::<NodeA>(false, &Default::default());
+        cx.set_direction(Some(opg::SchemaDirection::Response));
+        cx.mention_schema::<NodeB>(false, &Default::default());
+
+        assert_eq!(cx.verify_schemas(), Ok(()));
+        assert_eq!(
+            serde_yaml::to_string(cx).unwrap(),
+            r##"---
+schemas:
+  NodeARequest:
+    type: object
+    properties:
+      b:
+        allOf:
+          - $ref: "#/components/schemas/NodeBRequest"
+        nullable: true
+      tags:
+        type: array
+        items:
+          type: string
+        default: []
+    required:
+      - b
+  NodeAResponse:
+    type: object
+    properties:
+      b:
+        allOf:
+          - $ref: "#/components/schemas/NodeBResponse"
+        nullable: true
+      tags:
+        type: array
+        items:
+          type: string
+        default: []
+    required:
+      - tags
+      - b
+  NodeBRequest:
+    type: object
+    properties:
+      a:
+        type: array
+        items:
+          $ref: "#/components/schemas/NodeARequest"
+    required:
+      - a
+  NodeBResponse:
+    type: object
+    properties:
+      a:
+        type: array
+        items:
+          $ref: "#/components/schemas/NodeAResponse"
+    required:
+      - a
+"##
+        );
+    }
+
+    #[derive(Serialize, serde::Deserialize, OpgModel)]
+    #[serde(tag = "kind")]
+    enum AccountEvent {
+        Created(Account),
+        Updated { profile: Profile },
+        Deleted { id: u64 },
+    }
+
+    #[test]
+    fn directional_tagged_enum() {
+        let cx = &mut Components::new();
+        cx.set_direction(Some(opg::SchemaDirection::Request));
+        cx.mention_schema::<AccountEvent>(false, &Default::default());
+        cx.set_direction(Some(opg::SchemaDirection::Response));
+        cx.mention_schema::<AccountEvent>(false, &Default::default());
+
+        assert_eq!(cx.verify_schemas(), Ok(()));
+        assert_eq!(
+            serde_yaml::to_string(cx).unwrap(),
+            r##"---
+schemas:
+  AccountEventCreated:
+    type: object
+    properties:
+      email:
+        type: string
+      id:
+        type: integer
+        format: uint64
+        readOnly: true
+      kind:
+        description: AccountEvent type variant
+        type: string
+        enum:
+          - Created
+        example: Created
+      password:
+        type: string
+        writeOnly: true
+    required:
+      - id
+      - email
+      - password
+      - kind
+  AccountEventDeleted:
+    type: object
+    properties:
+      id:
+        type: integer
+        format: uint64
+      kind:
+        description: AccountEvent type variant
+        type: string
+        enum:
+          - Deleted
+        example: Deleted
+    required:
+      - id
+      - kind
+  AccountEventRequest:
+    oneOf:
+      - $ref: "#/components/schemas/AccountEventCreated"
+      - $ref: "#/components/schemas/AccountEventUpdatedRequest"
+      - $ref: "#/components/schemas/AccountEventDeleted"
+    discriminator:
+      propertyName: kind
+      mapping:
+        Created: "#/components/schemas/AccountEventCreated"
+        Deleted: "#/components/schemas/AccountEventDeleted"
+        Updated: "#/components/schemas/AccountEventUpdatedRequest"
+  AccountEventResponse:
+    oneOf:
+      - $ref: "#/components/schemas/AccountEventCreated"
+      - $ref: "#/components/schemas/AccountEventUpdatedResponse"
+      - $ref: "#/components/schemas/AccountEventDeleted"
+    discriminator:
+      propertyName: kind
+      mapping:
+        Created: "#/components/schemas/AccountEventCreated"
+        Deleted: "#/components/schemas/AccountEventDeleted"
+        Updated: "#/components/schemas/AccountEventUpdatedResponse"
+  AccountEventUpdatedRequest:
+    type: object
+    properties:
+      kind:
+        description: AccountEvent type variant
+        type: string
+        enum:
+          - Updated
+        example: Updated
+      profile:
+        $ref: "#/components/schemas/ProfileRequest"
+    required:
+      - profile
+      - kind
+  AccountEventUpdatedResponse:
+    type: object
+    properties:
+      kind:
+        description: AccountEvent type variant
+        type: string
+        enum:
+          - Updated
+        example: Updated
+      profile:
+        $ref: "#/components/schemas/ProfileResponse"
+    required:
+      - profile
+      - kind
+  ProfileRequest:
+    type: object
+    properties:
+      displayName:
+        type: string
+      tags:
+        type: array
+        items:
+          type: string
+        default: []
+    required:
+      - displayName
+  ProfileResponse:
+    type: object
+    properties:
+      created_at:
+        type: integer
+        format: uint64
+      display_name:
+        type: string
+      tags:
+        type: array
+        items:
+          type: string
+        default: []
+    required:
+      - display_name
+      - tags
+      - created_at
+"##
+        );
+    }
+
+    #[derive(Default, Serialize, serde::Deserialize, OpgModel)]
+    #[serde(rename_all = "lowercase")]
+    enum Level {
+        #[default]
+        Info,
+        Debug,
+    }
+
+    fn default_workers() -> u32 {
+        4
+    }
+
+    fn default_hosts() -> Vec<&'static str> {
+        vec!["localhost"]
+    }
+
+    #[derive(serde::Deserialize)]
+    struct Opaque(u32);
+
+    #[derive(Serialize, serde::Deserialize, OpgModel)]
+    struct LoggerConfig {
+        #[serde(default)]
+        level: Level,
+        #[serde(default = "default_workers")]
+        workers: u32,
+        #[opg(default = 8080)]
+        port: u16,
+        #[opg(default_with = "default_hosts()")]
+        hosts: Vec<String>,
+        #[serde(default, skip_serializing)]
+        #[opg(integer)]
+        opaque: Option<Opaque>,
+        path: String,
+    }
+
+    #[derive(Serialize, serde::Deserialize, OpgModel)]
+    #[serde(default)]
+    struct RetryPolicy {
+        attempts: u8,
+        backoff: String,
+    }
+
+    impl Default for RetryPolicy {
+        fn default() -> Self {
+            Self {
+                attempts: 3,
+                backoff: "exponential".to_owned(),
+            }
+        }
+    }
+
+    #[test]
+    fn default_values() {
+        let cx = &mut Components::new();
+
+        assert_eq!(
+            serde_yaml::to_string(&LoggerConfig::get_schema(cx)).unwrap(),
+            r##"---
+type: object
+properties:
+  hosts:
+    type: array
+    items:
+      type: string
+    default:
+      - localhost
+  level:
+    allOf:
+      - $ref: "#/components/schemas/Level"
+    default: info
+  opaque:
+    type: integer
+    writeOnly: true
+  path:
+    type: string
+  port:
+    type: integer
+    format: uint16
+    default: 8080
+  workers:
+    type: integer
+    format: uint32
+    default: 4
+required:
+  - path
+"##
+        );
+
+        assert_eq!(
+            serde_yaml::to_string(&RetryPolicy::get_schema(cx)).unwrap(),
+            r##"---
+type: object
+properties:
+  attempts:
+    type: integer
+    format: uint8
+    default: 3
+  backoff:
+    type: string
+    default: exponential
+"##
+        );
+    }
+
+    #[derive(Serialize, OpgModel)]
+    #[opg("Acknowledgement")]
+    struct Ack;
+
+    #[derive(Serialize, OpgModel)]
+    #[serde(untagged)]
+    enum Nothing {
+        Empty,
+        Missing,
+    }
+
+    #[derive(Serialize, OpgModel)]
+    #[serde(untagged)]
+    enum MaybeCount {
+        Unknown,
+        Count(u32),
+        Unlimited,
+        Range { from: u32, to: u32 },
+    }
+
+    #[test]
+    fn unit_types() {
+        use opg::{Model, ModelData};
+
+        let cx = &mut Components::new();
+        cx.mention_schema::<Ack>(false, &Default::default());
+        cx.mention_schema::<Nothing>(false, &Default::default());
+        cx.mention_schema::<MaybeCount>(false, &Default::default());
+
+        assert_eq!(
+            serde_yaml::to_string(cx).unwrap(),
+            r##"---
+schemas:
+  Ack:
+    description: Acknowledgement
+    nullable: true
+    enum:
+      - ~
+  MaybeCount:
+    oneOf:
+      - nullable: true
+        enum:
+          - ~
+      - type: integer
+        format: uint32
+      - type: object
+        properties:
+          from:
+            type: integer
+            format: uint32
+          to:
+            type: integer
+            format: uint32
+        required:
+          - from
+          - to
+  Nothing:
+    nullable: true
+    enum:
+      - ~
+"##
+        );
+
+        let model: Model =
+            serde_yaml::from_str(&serde_yaml::to_string(&Ack::get_schema(cx)).unwrap()).unwrap();
+        assert!(matches!(model.data, ModelData::Null(_)));
+        assert_eq!(model.description.as_deref(), Some("Acknowledgement"));
+
+        assert_eq!(
+            serde_yaml::to_string(&opg::json_schema::<MaybeCount>()).unwrap(),
+            r##"---
+$schema: "https://json-schema.org/draft/2020-12/schema"
+$ref: "#/$defs/MaybeCount"
+$defs:
+  MaybeCount:
+    oneOf:
+      - type: "null"
+      - type: integer
+        format: uint32
+      - type: object
+        properties:
+          from:
+            type: integer
+            format: uint32
+          to:
+            type: integer
+            format: uint32
+        required:
+          - from
+          - to
+"##
+        );
+    }
+
+    #[derive(Serialize, OpgModel)]
+    #[serde(rename_all = "lowercase")]
+    enum Color {
+        Red,
+        Green,
+    }
+
+    #[derive(Serialize, OpgModel)]
+    #[serde(untagged)]
+    enum Shade {
+        Named(Color),
+        Rgb { red: u8, green: u8, blue: u8 },
+    }
+
+    #[derive(Serialize, OpgModel)]
+    struct Palette {
+        #[opg("Main color of the palette")]
+        primary: Color,
+        secondary: Option<Color>,
+        #[opg("Optional shade", inline)]
+        shade: Option<Shade>,
+    }
+
+    #[test]
+    fn reference_params() {
+        let cx = &mut Components::new();
+        cx.mention_schema::<Palette>(false, &Default::default());
+
+        assert_eq!(
+            serde_yaml::to_string(cx).unwrap(),
+            r##"---
+schemas:
+  Color:
+    type: string
+    enum:
+      - red
+      - green
+    example: red
+  Palette:
+    type: object
+    properties:
+      primary:
+        description: Main color of the palette
+        allOf:
+          - $ref: "#/components/schemas/Color"
+      secondary:
+        allOf:
+          - $ref: "#/components/schemas/Color"
+        nullable: true
+      shade:
+        description: Optional shade
+        oneOf:
+          - $ref: "#/components/schemas/Color"
+          - type: object
+            properties:
+              blue:
+                type: integer
+                format: uint8
+              green:
+                type: integer
+                format: uint8
+              red:
+                type: integer
+                format: uint8
+            required:
+              - red
+              - green
+              - blue
+        nullable: true
+    required:
+      - primary
+      - secondary
+      - shade
+"##
+        );
+
+        assert_eq!(
+            serde_yaml::to_string(&opg::json_schema::<Palette>()["$defs"]["Palette"]).unwrap(),
+            r##"---
+type: object
+properties:
+  primary:
+    description: Main color of the palette
+    $ref: "#/$defs/Color"
+  secondary:
+    anyOf:
+      - $ref: "#/$defs/Color"
+      - type: "null"
+  shade:
+    description: Optional shade
+    oneOf:
+      - $ref: "#/$defs/Color"
+      - type: object
+        properties:
+          blue:
+            type: integer
+            format: uint8
+          green:
+            type: integer
+            format: uint8
+          red:
+            type: integer
+            format: uint8
+        required:
+          - red
+          - green
+          - blue
+      - type: "null"
+required:
+  - primary
+  - secondary
+  - shade
+"##
+        );
+    }
+
+    mod hex {
+        use opg::*;
+        use serde::Serializer;
+
+        pub fn serialize<S>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            let encoded: String = bytes.iter().map(|byte| format!("{:02x}", byte)).collect();
+            serializer.serialize_str(&encoded)
+        }
+
+        pub fn schema(_: &mut Components) -> ModelReference {
+            ModelReference::Inline(Model {
+                description: Some("Hex encoded bytes".to_owned()),
+                example: Some("deadbeef".into()),
+                default: None,
+                read_only: false,
+                write_only: false,
+                data: ModelData::Single(ModelType {
+                    nullable: false,
+                    type_description: ModelTypeDescription::String(ModelString {
+                        variants: None,
+                        data: ModelSimple {
+                            format: Some("hex".to_owned()),
+                            ..Default::default()
+                        },
+                        ..Default::default()
+                    }),
+                }),
+            })
+        }
+    }
+
+    struct Millis(std::time::SystemTime);
+
+    impl Serialize for Millis {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: serde::Serializer,
+        {
+            let elapsed = self.0.duration_since(std::time::UNIX_EPOCH).unwrap();
+            serializer.serialize_u64(elapsed.as_millis() as u64)
+        }
+    }
+
+    struct Labels(Vec<(String, String)>);
+
+    impl Serialize for Labels {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: serde::Serializer,
+        {
+            serializer.collect_map(self.0.iter().map(|(key, value)| (key, value)))
+        }
+    }
+
+    #[derive(Serialize, OpgModel)]
+    #[opg(schema_with = "hex::schema")]
+    struct Hash(#[serde(with = "hex")] Vec<u8>);
+
+    #[derive(Serialize, OpgModel)]
+    #[opg("Unix timestamp in milliseconds", as = "u64")]
+    struct Timestamp(Millis);
+
+    #[derive(Serialize, OpgModel)]
+    #[serde(untagged)]
+    enum Checksum {
+        #[opg(schema_with = "hex::schema")]
+        Digest(#[serde(with = "hex")] Vec<u8>),
+        Crc(u32),
+    }
+
+    #[derive(Serialize, OpgModel)]
+    #[serde(tag = "kind", rename_all = "lowercase")]
+    enum Source {
+        Url { url: String },
+        Labeled(#[opg(as = "std::collections::BTreeMap<String, String>")] Labels),
+    }
+
+    #[derive(Serialize, OpgModel)]
+    struct Artifact {
+        hash: Hash,
+        #[serde(with = "hex")]
+        #[opg(schema_with = "hex::schema")]
+        signature: Vec<u8>,
+        #[opg("Creation time", as = "u64")]
+        created_at: Millis,
+        updated_at: Timestamp,
+        checksum: Checksum,
+        source: Source,
+    }
+
+    #[test]
+    fn schema_overrides() {
+        let cx = &mut Components::new();
+        cx.mention_schema::<Artifact>(false, &Default::default());
+
+        assert_eq!(
+            serde_yaml::to_string(cx).unwrap(),
+            r##"---
+schemas:
+  Artifact:
+    type: object
+    properties:
+      checksum:
+        $ref: "#/components/schemas/Checksum"
+      created_at:
+        description: Creation time
+        type: integer
+        format: uint64
+      hash:
+        $ref: "#/components/schemas/Hash"
+      signature:
+        description: Hex encoded bytes
+        type: string
+        format: hex
+        example: deadbeef
+      source:
+        $ref: "#/components/schemas/Source"
+      updated_at:
+        $ref: "#/components/schemas/Timestamp"
+    required:
+      - hash
+      - signature
+      - created_at
+      - updated_at
+      - checksum
+      - source
+  Checksum:
+    oneOf:
+      - description: Hex encoded bytes
+        type: string
+        format: hex
+        example: deadbeef
+      - type: integer
+        format: uint32
+  Hash:
+    description: Hex encoded bytes
+    type: string
+    format: hex
+    example: deadbeef
+  Source:
+    oneOf:
+      - $ref: "#/components/schemas/SourceUrl"
+      - $ref: "#/components/schemas/SourceLabeled"
+    discriminator:
+      propertyName: kind
+      mapping:
+        labeled: "#/components/schemas/SourceLabeled"
+        url: "#/components/schemas/SourceUrl"
+  SourceLabeled:
+    type: object
+    properties:
+      kind:
+        description: Source type variant
+        type: string
+        enum:
+          - labeled
+        example: labeled
+    additionalProperties:
+      type: string
+    required:
+      - kind
+  SourceUrl:
+    type: object
+    properties:
+      kind:
+        description: Source type variant
+        type: string
+        enum:
+          - url
+        example: url
+      url:
+        type: string
+    required:
+      - url
+      - kind
+  Timestamp:
+    description: Unix timestamp in milliseconds
+    type: integer
+    format: uint64
+"##
+        );
+    }
+
+    #[derive(Serialize, OpgModel)]
+    struct Connection {
+        id: u128,
+        retries: std::num::NonZeroU8,
+        workers: std::num::NonZeroUsize,
+        shift: std::num::NonZeroI32,
+        offset: std::num::Wrapping<i64>,
+        priority: std::cmp::Reverse<u16>,
+        local: std::net::Ipv4Addr,
+        local_v6: std::net::Ipv6Addr,
+        remote: std::net::IpAddr,
+        peer: std::net::SocketAddr,
+        peer_v4: std::net::SocketAddrV4,
+        peer_v6: std::net::SocketAddrV6,
+        timeout: std::time::Duration,
+        opened_at: std::time::SystemTime,
+        socket: std::path::PathBuf,
+        name: std::borrow::Cow<'static, str>,
+        tags: std::collections::BTreeSet<String>,
+        state: std::sync::Mutex<bool>,
+        limit: std::sync::RwLock<u32>,
+        marker: std::marker::PhantomData<u8>,
+        last_result: Result<(), String>,
+        previous_result: Result<u8, String>,
+    }
+
+    #[test]
+    fn std_types() {
+        let connection = Connection {
+            id: 1,
+            retries: std::num::NonZeroU8::new(3).unwrap(),
+            workers: std::num::NonZeroUsize::new(4).unwrap(),
+            shift: std::num::NonZeroI32::new(-2).unwrap(),
+            offset: std::num::Wrapping(-1),
+            priority: std::cmp::Reverse(2),
+            local: std::net::Ipv4Addr::LOCALHOST,
+            local_v6: std::net::Ipv6Addr::LOCALHOST,
+            remote: std::net::Ipv6Addr::LOCALHOST.into(),
+            peer: ([127, 0, 0, 1], 8080).into(),
+            peer_v4: std::net::SocketAddrV4::new(std::net::Ipv4Addr::LOCALHOST, 8080),
+            peer_v6: std::net::SocketAddrV6::new(std::net::Ipv6Addr::LOCALHOST, 8080, 0, 0),
+            timeout: std::time::Duration::from_millis(1500),
+            opened_at: std::time::UNIX_EPOCH + std::time::Duration::from_secs(60),
+            socket: "/tmp/socket".into(),
+            name: "main".into(),
+            tags: vec!["fast".to_owned()].into_iter().collect(),
+            state: std::sync::Mutex::new(true),
+            limit: std::sync::RwLock::new(10),
+            marker: std::marker::PhantomData,
+            last_result: Err("refused".to_owned()),
+            previous_result: Ok(200),
+        };
+
+        assert_eq!(
+            serde_yaml::to_string(&connection).unwrap(),
+            r##"---
+id: 1
+retries: 3
+workers: 4
+shift: -2
+offset: -1
+priority: 2
+local: 127.0.0.1
+local_v6: "::1"
+remote: "::1"
+peer: "127.0.0.1:8080"
+peer_v4: "127.0.0.1:8080"
+peer_v6: "[::1]:8080"
+timeout:
+  secs: 1
+  nanos: 500000000
+opened_at:
+  secs_since_epoch: 60
+  nanos_since_epoch: 0
+socket: /tmp/socket
+name: main
+tags:
+  - fast
+state: true
+limit: 10
+marker: ~
+last_result:
+  Err: refused
+previous_result:
+  Ok: 200
+"##
+        );
+
+        let cx = &mut Components::new();
+
+        assert_eq!(
+            serde_yaml::to_string(&Connection::get_schema(cx)).unwrap(),
+            r##"---
+type: object
+properties:
+  id:
+    type: integer
+    format: uint128
+  last_result:
+    oneOf:
+      - type: object
+        properties:
+          Ok:
+            nullable: true
+            enum:
+              - ~
+        required:
+          - Ok
+      - type: object
+        properties:
+          Err:
+            type: string
+        required:
+          - Err
+  limit:
+    type: integer
+    format: uint32
+  local:
+    type: string
+    format: ipv4
+    example: 127.0.0.1
+  local_v6:
+    type: string
+    format: ipv6
+    example: "::1"
+  marker:
+    nullable: true
+    enum:
+      - ~
+  name:
+    type: string
+  offset:
+    type: integer
+    format: int64
+  opened_at:
+    description: Time elapsed since the unix epoch
+    type: object
+    properties:
+      nanos_since_epoch:
+        type: integer
+        format: uint32
+      secs_since_epoch:
+        type: integer
+        format: uint64
+    required:
+      - secs_since_epoch
+      - nanos_since_epoch
+  peer:
+    description: IP address with port
+    type: string
+    example: "127.0.0.1:8080"
+  peer_v4:
+    description: IPv4 address with port
+    type: string
+    example: "127.0.0.1:8080"
+  peer_v6:
+    description: IPv6 address with port
+    type: string
+    example: "[::1]:8080"
+  previous_result:
+    oneOf:
+      - type: object
+        properties:
+          Ok:
+            type: integer
+            format: uint8
+        required:
+          - Ok
+      - type: object
+        properties:
+          Err:
+            type: string
+        required:
+          - Err
+  priority:
+    type: integer
+    format: uint16
+  remote:
+    oneOf:
+      - type: string
+        format: ipv4
+        example: 127.0.0.1
+      - type: string
+        format: ipv6
+        example: "::1"
+  retries:
+    type: integer
+    format: uint8
+    minimum: 1
+  shift:
+    type: integer
+    format: int32
+  socket:
+    type: string
+  state:
+    type: boolean
+  tags:
+    type: array
+    items:
+      type: string
+  timeout:
+    type: object
+    properties:
+      nanos:
+        type: integer
+        format: uint32
+      secs:
+        type: integer
+        format: uint64
+    required:
+      - secs
+      - nanos
+  workers:
+    type: integer
+    minimum: 1
+required:
+  - id
+  - retries
+  - workers
+  - shift
+  - offset
+  - priority
+  - local
+  - local_v6
+  - remote
+  - peer
+  - peer_v4
+  - peer_v6
+  - timeout
+  - opened_at
+  - socket
+  - name
+  - tags
+  - state
+  - limit
+  - marker
+  - last_result
+  - previous_result
+"##
+        );
+    }
+
+    #[derive(Serialize, OpgModel)]
+    struct Schedule {
+        created_at: chrono::DateTime<chrono::Utc>,
+        starts_at: chrono::NaiveDateTime,
+        day: chrono::NaiveDate,
+        alarm: chrono::NaiveTime,
+        length: chrono::Duration,
+        #[serde(with = "chrono::serde::ts_seconds")]
+        #[opg(schema_with = "opg::schema_with::chrono::ts_seconds")]
+        updated_at: chrono::DateTime<chrono::Utc>,
+        #[serde(with = "chrono::serde::ts_milliseconds_option")]
+        #[opg(schema_with = "opg::schema_with::chrono::ts_milliseconds_option")]
+        deleted_at: Option<chrono::DateTime<chrono::Utc>>,
+    }
+
+    #[test]
+    fn chrono_types() {
+        let datetime = chrono::DateTime::from_timestamp(1593180260, 730045106).unwrap();
+        let schedule = Schedule {
+            created_at: datetime,
+            starts_at: datetime.naive_utc(),
+            day: datetime.date_naive(),
+            alarm: datetime.time(),
+            length: chrono::Duration::milliseconds(1500),
+            updated_at: datetime,
+            deleted_at: None,
+        };
+
+        assert_eq!(
+            serde_yaml::to_string(&schedule).unwrap(),
+            r##"---
+created_at: "2020-06-26T14:04:20.730045106Z"
+starts_at: "2020-06-26T14:04:20.730045106"
+day: 2020-06-26
+alarm: "14:04:20.730045106"
+length:
+  - 1
+  - 500000000
+updated_at: 1593180260
+deleted_at: ~
+"##
+        );
+
+        let cx = &mut Components::new();
+
+        assert_eq!(
+            serde_yaml::to_string(&Schedule::get_schema(cx)).unwrap(),
+            r##"---
+type: object
+properties:
+  alarm:
+    description: Time without timezone
+    type: string
+    format: time
+    example: "14:04:20.730045106"
+  created_at:
+    description: Datetime with timezone
+    type: string
+    format: date-time
+    example: "2020-06-26T14:04:20.730045106Z"
+  day:
+    description: Date without timezone
+    type: string
+    format: date
+    example: 2020-06-26
+  deleted_at:
+    description: Unix timestamp in milliseconds
+    nullable: true
+    type: integer
+    format: int64
+  length:
+    description: Duration as seconds and nanoseconds
+    type: array
+    items:
+      oneOf:
+        - type: integer
+          format: int64
+        - type: integer
+          format: int32
+  starts_at:
+    description: Datetime without timezone
+    type: string
+    format: date-time
+    example: "2020-06-26T14:04:20.730045106"
+  updated_at:
+    description: Unix timestamp in seconds
+    type: integer
+    format: int64
+required:
+  - created_at
+  - starts_at
+  - day
+  - alarm
+  - length
+  - updated_at
+  - deleted_at
+"##
+        );
+    }
+
+    #[derive(Serialize, OpgModel)]
+    struct Meeting {
+        created_at: time::OffsetDateTime,
+        starts_at: time::PrimitiveDateTime,
+        day: time::Date,
+        alarm: time::Time,
+        length: time::Duration,
+        #[serde(with = "time::serde::rfc3339")]
+        #[opg(schema_with = "opg::schema_with::time::rfc3339")]
+        updated_at: time::OffsetDateTime,
+        #[serde(with = "time::serde::timestamp::milliseconds::option")]
+        #[opg(schema_with = "opg::schema_with::time::timestamp::milliseconds::option")]
+        deleted_at: Option<time::OffsetDateTime>,
+    }
+
+    #[test]
+    fn time_types() {
+        let datetime = time::macros::datetime!(2020-06-26 14:04:20.730045106 UTC);
+        let meeting = Meeting {
+            created_at: datetime,
+            starts_at: time::PrimitiveDateTime::new(datetime.date(), datetime.time()),
+            day: datetime.date(),
+            alarm: datetime.time(),
+            length: time::Duration::milliseconds(1500),
+            updated_at: datetime,
+            deleted_at: Some(datetime),
+        };
+
+        assert_eq!(
+            serde_yaml::to_string(&meeting).unwrap(),
+            r##"---
+created_at: "2020-06-26 14:04:20.730045106 +00:00:00"
+starts_at: "2020-06-26 14:04:20.730045106"
+day: 2020-06-26
+alarm: "14:04:20.730045106"
+length: "1.500000000"
+updated_at: "2020-06-26T14:04:20.730045106Z"
+deleted_at: 1593180260730
+"##
+        );
+
+        let cx = &mut Components::new();
+
+        assert_eq!(
+            serde_yaml::to_string(&Meeting::get_schema(cx)).unwrap(),
+            r##"---
+type: object
+properties:
+  alarm:
+    description: Time without offset
+    type: string
+    format: time
+    example: "14:04:20.730045106"
+  created_at:
+    description: Datetime with offset
+    type: string
+    example: "2020-06-26 14:04:20.730045106 +00:00:00"
+  day:
+    description: Date without offset
+    type: string
+    format: date
+    example: 2020-06-26
+  deleted_at:
+    description: Unix timestamp in milliseconds
+    nullable: true
+    type: integer
+    format: int64
+  length:
+    description: Duration in seconds with nanosecond precision
+    type: string
+    example: "1.500000000"
+  starts_at:
+    description: Datetime without offset
+    type: string
+    example: "2020-06-26 14:04:20.730045106"
+  updated_at:
+    description: Datetime in RFC 3339 format
+    type: string
+    format: date-time
+    example: "2020-06-26T14:04:20.730045106Z"
+required:
+  - created_at
+  - starts_at
+  - day
+  - alarm
+  - length
+  - updated_at
+  - deleted_at
+"##
+        );
+    }
+
+    #[derive(Serialize, OpgModel)]
+    struct Event {
+        payload: opg::serde_json::Value,
+        #[opg("Free-form attributes")]
+        attributes: opg::serde_json::Map<String, opg::serde_json::Value>,
+        score: opg::serde_json::Number,
+        raw: Box<opg::serde_json::value::RawValue>,
+        #[opg("Optional context")]
+        context: Option<opg::serde_json::Value>,
+    }
+
+    #[test]
+    fn json_values() {
+        let event = Event {
+            payload: opg::serde_json::json!({ "id": 1 }),
+            attributes: vec![("source".to_owned(), "api".into())]
+                .into_iter()
+                .collect(),
+            score: opg::serde_json::Number::from_f64(0.5).unwrap(),
+            raw: opg::serde_json::value::RawValue::from_string("[1,2]".to_owned()).unwrap(),
+            context: None,
+        };
+
+        assert_eq!(
+            opg::serde_json::to_string(&event).unwrap(),
+            r##"{"payload":{"id":1},"attributes":{"source":"api"},"score":0.5,"raw":[1,2],"context":null}"##
+        );
+
+        let cx = &mut Components::new();
+
+        let schema = serde_yaml::to_string(&Event::get_schema(cx)).unwrap();
+        let parsed: opg::Model = serde_yaml::from_str(&schema).unwrap();
+        assert_eq!(serde_yaml::to_string(&parsed).unwrap(), schema);
+        assert_eq!(
+            schema,
+            r##"---
+type: object
+properties:
+  attributes:
+    description: Free-form attributes
+    type: object
+    additionalProperties: {}
+  context:
+    description: Optional context
+  payload: {}
+  raw: {}
+  score:
+    type: number
+required:
+  - payload
+  - attributes
+  - score
+  - raw
+  - context
+"##
+        );
+    }
 }
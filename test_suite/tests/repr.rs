@@ -26,28 +26,28 @@ mod tests {
 oneOf:
   - description: IssuedLoansAndCredits variant
     type: integer
-    example: "5586"
+    example: 5586
   - description: LiabilitiesForSale variant
     type: integer
-    example: "4909"
+    example: 4909
   - description: InterestRateOnIssuedLoansAndCredits variant
     type: integer
-    example: "55861"
+    example: 55861
   - description: OtherIncomesFromCoreActivity variant
     type: integer
-    example: "819"
+    example: 819
   - description: FinesOnIssuedLoansAndCredits variant
     type: integer
-    example: "55862"
+    example: 55862
   - description: InterestIncomes variant
     type: integer
-    example: "700"
+    example: 700
   - description: CurrentAccounts variant
     type: integer
-    example: "651"
+    example: 651
   - description: Pledge variant
     type: integer
-    example: "8"
+    example: 8
 "##
         );
     }